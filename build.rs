@@ -0,0 +1,110 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, fs, path::Path};
+
+/// Generates the tests that `example_tests!(DAY)` includes: one per example in `src/examples`
+/// and part that has an expected answer in the example's `.expected` sidecar.
+/// Examples of other years in `src/{year}/examples` are generated for `example_tests!(DAY, year = YEAR)`.
+fn main() {
+    // scaffolding a day of a new year registers it in `src/days.rs`.
+    println!("cargo:rerun-if-changed=src/days.rs");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set for build scripts");
+    write_tests(
+        Path::new("src/examples"),
+        "advent_of_code::DEFAULT_YEAR",
+        &out_dir,
+        "",
+    );
+
+    for year in years(Path::new("src")) {
+        write_tests(
+            &Path::new("src").join(&year).join("examples"),
+            &year,
+            &out_dir,
+            &format!("{year}_"),
+        );
+    }
+}
+
+/// The directories in `src` that are named like a year.
+fn years(src: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(src) else {
+        return vec![];
+    };
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name.len() == 4 && name.parse::<u16>().is_ok())
+        .collect()
+}
+
+/// Writes `example_tests_{prefix}{day}.rs` for every day, even those without examples.
+fn write_tests(dir: &Path, year: &str, out_dir: &str, prefix: &str) {
+    // a missing path would make cargo rerun this script on every build.
+    if dir.exists() {
+        println!("cargo:rerun-if-changed={}", dir.display());
+    }
+
+    let mut tests: Vec<String> = vec![String::new(); 26];
+
+    if let Ok(entries) = fs::read_dir(dir) {
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .collect();
+        names.sort();
+
+        for name in names {
+            if let Some(day) = name.strip_suffix("-1.txt") {
+                println!(
+                    "cargo:warning=\"{}\" is never read, the first example of a day is \"{day}.txt\".",
+                    dir.join(&name).display()
+                );
+                continue;
+            }
+            let Some((day, example)) = parse_example_name(&name) else {
+                continue;
+            };
+            let sidecar = dir.join(name.replace(".txt", ".expected"));
+            let Ok(expected) = fs::read_to_string(sidecar) else {
+                continue;
+            };
+
+            for (part, answer) in expected.lines().take(2).enumerate() {
+                if answer.trim().is_empty() {
+                    continue;
+                }
+                let part = part + 1;
+                let solver = if part == 1 { "part_one" } else { "part_two" };
+                let test_name = match example {
+                    Some(example) => format!("example_{example}_{solver}"),
+                    None => format!("example_{solver}"),
+                };
+                tests[usize::from(day)].push_str(&format!(
+                    "#[test]\nfn {test_name}() {{\n    advent_of_code::template::examples::check_example({year}, {day}, {example:?}, {part}, {solver});\n}}\n\n"
+                ));
+            }
+        }
+    }
+
+    for (day, tests) in tests.iter().enumerate().skip(1) {
+        let path = Path::new(out_dir).join(format!("example_tests_{prefix}{day}.rs"));
+        fs::write(path, tests).expect("could not write generated example tests");
+    }
+}
+
+/// Reads `NN.txt` as `(NN, None)` and `NN-K.txt` as `(NN, Some(K))`.
+/// `NN-1.txt` is left out, the first example of a day is `NN.txt`, see `write_tests`.
+fn parse_example_name(name: &str) -> Option<(u8, Option<u8>)> {
+    let stem = name.strip_suffix(".txt")?;
+    let (day, example) = match stem.split_once('-') {
+        Some((day, example)) => match example.parse::<u8>() {
+            Ok(example @ 2..) => (day, Some(example)),
+            _ => return None,
+        },
+        None => (stem, None),
+    };
+    let day: u8 = day.parse().ok()?;
+    (1..=25).contains(&day).then_some((day, example))
+}
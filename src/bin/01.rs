@@ -20,11 +20,7 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(sums.iter().sorted_by_key(|w| Reverse(*w)).take(3).sum())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(1);
 
 #[cfg(test)]
 mod tests {
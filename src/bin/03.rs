@@ -48,11 +48,7 @@ pub fn part_two(input: &str) -> Option<u32> {
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(3);
 
 #[cfg(test)]
 mod tests {
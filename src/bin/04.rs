@@ -37,11 +37,7 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(input.lines().map(check_overlap).sum())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(4);
 
 #[cfg(test)]
 mod tests {
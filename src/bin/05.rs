@@ -76,11 +76,7 @@ pub fn part_two(input: &str) -> Option<String> {
     Some(answer.into_iter().collect::<String>())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(5);
 
 #[cfg(test)]
 mod tests {
@@ -31,11 +31,7 @@ pub fn part_two(input: &str) -> Option<i32> {
     calculate_start_packet(input, 14)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(6);
 
 #[cfg(test)]
 mod tests {
@@ -61,11 +61,7 @@ pub fn part_two(input: &str) -> Option<i32> {
     Some(closest_dir)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(7);
 
 #[cfg(test)]
 mod tests {
@@ -9,7 +9,7 @@ fn create_trees_array(input: &str) -> Ttrees {
         .lines()
         .flat_map(|c| {
             c.chars()
-                .map(|a| ((a as i32 - 0x30) as i16))
+                .map(|a| (a as i32 - 0x30) as i16)
                 .collect::<Vec<i16>>()
         })
         .collect_vec();
@@ -113,11 +113,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         .max()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 8);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(8);
 
 #[cfg(test)]
 mod tests {
@@ -101,11 +101,8 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(positions.len() as u32)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(9);
+advent_of_code::example_tests!(9);
 
 #[cfg(test)]
 mod tests {
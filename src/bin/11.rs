@@ -150,11 +150,7 @@ pub fn part_two(input: &str) -> Option<i64> {
     Some(monkeys[0].nr_inspect.get() * monkeys[1].nr_inspect.get())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(11);
 
 #[cfg(test)]
 mod tests {
@@ -46,7 +46,7 @@ fn manhatten_dist(a: (i32, i32), b: (i32, i32)) -> i32 {
     (a.0 - b.0).abs() + (a.1 - b.1).abs()
 }
 
-fn a_star_search(start: Node, end: (i32, i32), map: &Vec<Vec<i32>>) -> i32 {
+fn a_star_search(start: Node, end: (i32, i32), map: &[Vec<i32>]) -> i32 {
     // apply alpha star search
     let mut open: Vec<Node> = vec![start];
     let mut closed: Vec<Node> = vec![];
@@ -127,9 +127,9 @@ pub fn part_two(input: &str) -> Option<i32> {
     let mut open: Vec<Node> = vec![];
     let mut closed: Vec<Node> = vec![];
 
-    for i in 0..map.len() {
-        for j in 0..map[0].len() {
-            if map[i][j] == 0 {
+    for (i, row) in map.iter().enumerate() {
+        for (j, height) in row.iter().enumerate() {
+            if *height == 0 {
                 open.push(Node {
                     g: 0,
                     h: manhatten_dist((i as i32, j as i32), end),
@@ -194,11 +194,7 @@ pub fn part_two(input: &str) -> Option<i32> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 12);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(12);
 
 #[cfg(test)]
 mod tests {
@@ -89,11 +89,7 @@ pub fn part_two(input: &str) -> Option<i32> {
         .reduce(|a, b| a * b)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(13);
 
 #[cfg(test)]
 mod tests {
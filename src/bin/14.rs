@@ -113,11 +113,7 @@ pub fn part_two(input: &str) -> Option<i32> {
     Some(simulate_sand(cave, min_x))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 14);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(14);
 
 #[cfg(test)]
 mod tests {
@@ -104,11 +104,7 @@ pub fn part_two(input: &str) -> Option<i64> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 15);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(15);
 
 #[cfg(test)]
 mod tests {
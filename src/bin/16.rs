@@ -252,11 +252,7 @@ pub fn part_two(input: &str) -> Option<i32> {
     Some(pressure)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 16);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(16, slow);
 
 #[cfg(test)]
 mod tests {
@@ -1,6 +1,8 @@
 use hashbrown::HashMap;
 use ndarray::prelude::*;
 
+type RockJetCombinations = HashMap<(usize, usize), Vec<(usize, usize, isize)>>;
+
 fn check_rock_blocked(
     cave: &Array2<i8>,
     rock_format: &Vec<(isize, isize)>,
@@ -123,8 +125,7 @@ fn simulate_rock_with_pattern(input: &str, nr_rocks: usize) -> i64 {
     let mut heighest_rock_position: isize = 0;
     let mut rock_height_saved = 0;
 
-    let mut rock_jet_combinations: HashMap<(usize, usize), Vec<(usize, usize, isize)>> =
-        Default::default();
+    let mut rock_jet_combinations: RockJetCombinations = Default::default();
     let mut i = 0;
     while i < nr_rocks {
         let current_rock = &rocks_formations[i % 5];
@@ -215,11 +216,7 @@ pub fn part_two(input: &str) -> Option<i64> {
     Some(simulate_rock_with_pattern(input, 10_usize.pow(12)))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 17);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(17);
 
 #[cfg(test)]
 mod tests {
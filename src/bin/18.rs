@@ -1,8 +1,7 @@
-#[macro_use]
-extern crate impl_ops;
 use std::{collections::VecDeque, ops};
 
 use hashbrown::HashSet;
+use impl_ops::*;
 use itertools::Itertools;
 use ndarray::Array3;
 
@@ -189,11 +188,7 @@ pub fn part_two(input: &str) -> Option<i32> {
     Some(nr_sides)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 18);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(18);
 
 #[cfg(test)]
 mod tests {
@@ -74,8 +74,9 @@ struct Factory {
     min_left: u32,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Default)]
 enum Actions {
+    #[default]
     Noop,
     BuildOre,
     BuildClay,
@@ -83,12 +84,6 @@ enum Actions {
     BuildGeode,
 }
 
-impl Default for Actions {
-    fn default() -> Self {
-        Self::Noop
-    }
-}
-
 impl Factory {
     fn new(min_left: u32) -> Self {
         Self {
@@ -222,7 +217,6 @@ impl Factory {
     fn bound(self, blueprint: &BluePrint) -> u32 {
         // with infinite money and clay how many geodes could we produce
         (0..self.min_left)
-            .into_iter()
             .rev()
             .fold(
                 (
@@ -274,11 +268,7 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(score)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 19);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(19, slow);
 
 #[cfg(test)]
 mod tests {
@@ -6,7 +6,7 @@ fn parse_numbers(input: &str) -> Vec<i64> {
 
 type MixedNumbers = VecDeque<(usize, i64)>;
 
-fn mix_numbers(mut new_numbers: MixedNumbers, numbers: &Vec<i64>) -> MixedNumbers {
+fn mix_numbers(mut new_numbers: MixedNumbers, numbers: &[i64]) -> MixedNumbers {
     let length_numbers = new_numbers.len() as i64;
 
     for (i, num) in numbers.iter().enumerate() {
@@ -75,11 +75,7 @@ pub fn part_two(input: &str) -> Option<i64> {
     Some(get_key_numbers(new_numbers))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 20);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(20);
 
 #[cfg(test)]
 mod tests {
@@ -185,11 +185,7 @@ pub fn part_two(input: &str) -> Option<i64> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 21);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(21);
 
 #[cfg(test)]
 mod tests {
@@ -1,8 +1,9 @@
-#[macro_use]
-extern crate impl_ops;
+// part two (folding the map onto a cube) is still work in progress.
+#![allow(dead_code, clippy::upper_case_acronyms)]
 use std::{collections::VecDeque, ops};
 
 use hashbrown::HashSet;
+use impl_ops::*;
 use itertools::enumerate;
 use ndarray::prelude::*;
 
@@ -129,7 +130,7 @@ fn find_position(
     direction: &Coords,
     maze_size: &Coords,
 ) -> Option<Coords> {
-    let mut new_position: Coords = position.clone();
+    let mut new_position: Coords = *position;
     // dbg!(new_position, position, direction);
 
     loop {
@@ -157,7 +158,7 @@ fn print_maze(maze: &Maze) {
                 _ => (),
             }
         }
-        print!("\n");
+        println!();
     }
 }
 
@@ -178,8 +179,7 @@ fn simulate_instructions(maze: &Maze, instructions: &Vec<Instruction>) -> (Coord
         match instruction {
             Instruction::Steps(n) => {
                 for _ in 0..*n {
-                    if let Some(new_pos) = find_position(&maze, &position, &direction, &array_size)
-                    {
+                    if let Some(new_pos) = find_position(maze, &position, &direction, &array_size) {
                         position = new_pos;
                     } else {
                         // is blocked thus can break
@@ -188,7 +188,7 @@ fn simulate_instructions(maze: &Maze, instructions: &Vec<Instruction>) -> (Coord
                 }
             }
             Instruction::Direction(dir) => {
-                direction = direction.rotate(&dir);
+                direction = direction.rotate(dir);
             }
         }
     }
@@ -216,7 +216,7 @@ pub fn part_one(input: &str) -> Option<i32> {
     Some(outcome)
 }
 
-fn get_side_oncube(pos: Coords) {}
+fn get_side_oncube(_pos: Coords) {}
 
 fn find_position_cube(
     maze: &Maze,
@@ -224,7 +224,7 @@ fn find_position_cube(
     direction: &Coords,
     maze_size: &Coords,
 ) -> Option<Coords> {
-    let mut new_position: Coords = position.clone();
+    let mut new_position: Coords = *position;
     // dbg!(new_position, position, direction);
 
     loop {
@@ -270,7 +270,7 @@ fn fold_cube(maze: &Maze) {
         small_maze.push(new_row);
     }
 
-    dbg!(small_maze);
+    dbg!(&small_maze);
 
     // let mut cube = HashMap;
 
@@ -310,7 +310,7 @@ fn fold_cube(maze: &Maze) {
 
 pub fn part_two(input: &str) -> Option<i32> {
     let maze = parse_maze(input);
-    let instructions = parse_instructions(input);
+    let _instructions = parse_instructions(input);
 
     // fold cube to get matching sides
     fold_cube(&maze);
@@ -320,11 +320,7 @@ pub fn part_two(input: &str) -> Option<i32> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 22);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(22);
 
 #[cfg(test)]
 mod tests {
@@ -1,9 +1,7 @@
-#[macro_use]
-extern crate impl_ops;
-
 use std::{cell::Cell, collections::VecDeque, ops};
 
 use hashbrown::HashMap;
+use impl_ops::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Position(i32, i32);
@@ -191,11 +189,7 @@ pub fn part_two(input: &str) -> Option<i32> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 23);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(23);
 
 #[cfg(test)]
 mod tests {
@@ -260,11 +260,7 @@ pub fn part_two(input: &str) -> Option<i32> {
     Some(steps)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 24);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(24);
 
 #[cfg(test)]
 mod tests {
@@ -108,15 +108,11 @@ pub fn part_one(input: &str) -> Option<String> {
     Some(snafu.to_text())
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+pub fn part_two(_input: &str) -> Option<String> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 25);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(25);
 
 #[cfg(test)]
 mod tests {
@@ -2,104 +2,64 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
-use std::{fs, process};
+use advent_of_code::template::{
+    aoc_client::{get_puzzle_path, AocClient},
+    input_cache::InputCache,
+    runner::finish_args,
+};
+use std::process;
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: Option<u16>,
+    /// Fetch the input again, even if it is cached or was edited by hand.
+    force: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
+    let parsed = Args {
         day: args.free_from_str()?,
         year: args.opt_value_from_str(["-y", "--year"])?,
-    })
-}
-
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
+        force: args.contains("--force"),
+    };
+    finish_args(args)?;
+    Ok(parsed)
 }
 
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
 }
 
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
-        Err(e) => {
-            eprintln!("Failed to process arguments: {e}");
-            exit_with_status(1, &tmp_file_path);
-        }
+        Err(e) => exit_with_error(format!("Failed to process arguments: {e}")),
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{day_padded}.txt");
-
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
-    }
+    let client = AocClient::from_env(args.year).unwrap_or_else(|e| exit_with_error(e));
+    let mut cache = InputCache::open_year(client.year())
+        .unwrap_or_else(|e| exit_with_error(format!("could not open input cache: {e}")));
 
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
-
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
-
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
-            exit_with_status(1, &tmp_file_path);
-        }
-    }
+    let fetched_input = client
+        .download(args.day, &mut cache, args.force)
+        .unwrap_or_else(|e| exit_with_error(format!("failed to download day {}: {e}", args.day)));
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
-        }
-        Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {e}");
-            exit_with_status(1, &tmp_file_path);
-        }
+    println!("---");
+    if fetched_input {
+        println!(
+            "🎄 Successfully wrote input to \"{}\".",
+            cache.path(args.day).display()
+        );
+    } else {
+        println!(
+            "🎄 Input at \"{}\" is already downloaded.",
+            cache.path(args.day).display()
+        );
     }
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        get_puzzle_path(client.year(), args.day).display()
+    );
 }
@@ -0,0 +1,110 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::template::{
+    aoc_client::{get_puzzle_path, resolve_year},
+    examples::{extract_examples, write_examples, Example},
+    runner::finish_args,
+};
+use advent_of_code::DEFAULT_YEAR;
+use std::{fs, process};
+
+struct Args {
+    day: u8,
+    year: Option<u16>,
+    /// Write the examples to `src/examples` instead of only printing them.
+    write: bool,
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        day: args.free_from_str()?,
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        write: args.contains("--write"),
+        force: args.contains("--force"),
+    };
+    finish_args(args)?;
+    Ok(parsed)
+}
+
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
+
+/// The expression a test reads example `index` with.
+fn read_expression(year: u16, day: u8, index: usize) -> String {
+    match index {
+        0 if year == DEFAULT_YEAR => format!("advent_of_code::read_file(\"examples\", {day})"),
+        0 => format!("advent_of_code::read_year_file({year}, \"examples\", {day})"),
+        index if year == DEFAULT_YEAR => {
+            format!("advent_of_code::read_example({day}, {})", index + 1)
+        }
+        index => format!(
+            "advent_of_code::read_year_example({year}, {day}, {})",
+            index + 1
+        ),
+    }
+}
+
+fn print_assertions(year: u16, day: u8, index: usize, example: &Example) {
+    for (solver, answer) in ["part_one", "part_two"].iter().zip(&example.answers) {
+        let Some(answer) = answer else { continue };
+        let expected = match answer.parse::<i64>() {
+            Ok(_) => answer.clone(),
+            Err(_) => format!("{answer:?}.to_string()"),
+        };
+        println!(
+            "assert_eq!({solver}(&{}), Some({expected}));",
+            read_expression(year, day, index)
+        );
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(format!("Failed to process arguments: {e}")),
+    };
+    let (year, day) = (resolve_year(args.year), args.day);
+
+    let puzzle_path = get_puzzle_path(year, day);
+    let puzzle = fs::read_to_string(&puzzle_path).unwrap_or_else(|e| {
+        exit_with_error(format!(
+            "could not read \"{}\": {e}. Run `cargo download {day}{}` to fetch it.",
+            puzzle_path.display(),
+            advent_of_code::year_flag(year)
+        ))
+    });
+
+    let examples = extract_examples(&puzzle);
+    if examples.is_empty() {
+        exit_with_error(format!(
+            "found no example in \"{}\".",
+            puzzle_path.display()
+        ));
+    }
+
+    for (index, example) in examples.iter().enumerate() {
+        println!("🎄 Example {} 🎄", index + 1);
+        print!("{}", example.input);
+        println!("---");
+        print_assertions(year, day, index, example);
+        println!();
+    }
+
+    if args.write {
+        let paths = write_examples(year, day, &examples, args.force)
+            .unwrap_or_else(|e| exit_with_error(format!("could not write examples: {e}")));
+        for path in paths {
+            println!("🎄 Successfully wrote example to \"{}\".", path.display());
+        }
+        match year {
+            DEFAULT_YEAR => println!("🎄 Add `advent_of_code::example_tests!({day});` to \"src/bin/{day:02}.rs\" to test them."),
+            year => println!("🎄 Add `advent_of_code::example_tests!({day}, year = {year});` to \"src/bin/{year}-{day:02}.rs\" to test them."),
+        }
+    }
+}
@@ -2,8 +2,12 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::template::{
+    aoc_client::{get_puzzle_path, resolve_year, AocClient},
+    markdown,
+    runner::finish_args,
+};
+use std::{fs, io, process};
 
 struct Args {
     day: u8,
@@ -12,10 +16,12 @@ struct Args {
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
+    let parsed = Args {
         day: args.free_from_str()?,
         year: args.opt_value_from_str(["-y", "--year"])?,
-    })
+    };
+    finish_args(args)?;
+    Ok(parsed)
 }
 
 fn main() {
@@ -27,19 +33,21 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+    let year = resolve_year(args.year);
 
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
+    // the puzzle is only fetched if `cargo download` didn't store it yet.
+    let puzzle = match fs::read_to_string(get_puzzle_path(year, args.day)) {
+        Ok(puzzle) => Ok(puzzle),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            AocClient::from_env(Some(year)).and_then(|client| client.puzzle(args.day))
         }
+        Err(e) => Err(e.into()),
+    };
+
+    match puzzle {
+        Ok(puzzle) => print!("{}", markdown::to_terminal(&puzzle)),
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
+            eprintln!("failed to read day {}: {e}", args.day);
             process::exit(1);
         }
     }
@@ -2,25 +2,37 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::{
+    template::{
+        aoc_client::{get_puzzle_path, resolve_year, AocClient},
+        examples::{extract_examples, write_examples},
+        input_cache::InputCache,
+        runner::finish_args,
+    },
+    DEFAULT_YEAR,
+};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs, io,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<i32> {
+/// The solution and tests shared by all templates. `{{PARSER}}` is replaced with the skeleton
+/// of a template, `{{PARSE}}` with the call of it that starts both parts, `{{TYPE}}` with the
+/// answer type and `{{DAY}}` with the day.
+/// Days of another year than `DEFAULT_YEAR` get that year passed to the macros and `read_file`.
+const MODULE_TEMPLATE: &str = r###"{{PARSER}}pub fn part_one(input: &str) -> Option<{{TYPE}}> {
+    {{PARSE}}
     None
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
+pub fn part_two(input: &str) -> Option<{{TYPE}}> {
+    {{PARSE}}
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!({{DAY}});
+advent_of_code::example_tests!({{DAY}});
 
 #[cfg(test)]
 mod tests {
@@ -28,87 +40,347 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = advent_of_code::read_file("examples", {{DAY}});
         assert_eq!(part_one(&input), None);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = advent_of_code::read_file("examples", {{DAY}});
         assert_eq!(part_two(&input), None);
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+const LINES_PARSER: &str = r###"fn parse_input(input: &str) -> Vec<&str> {
+    input.lines().collect()
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+"###;
+
+/// A grid of characters, like the ones of days 8, 14 and 22.
+const GRID_PARSER: &str = r###"use advent_of_code::helpers::Grid;
+
+fn parse_grid(input: &str) -> Grid<char> {
+    Grid::parse(input)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+"###;
+
+/// Groups of lines separated by blank lines, like the ones of days 1, 11 and 13.
+const BLOCKS_PARSER: &str = r###"fn parse_blocks(input: &str) -> Vec<Vec<&str>> {
+    input
+        .split("\n\n")
+        .map(|block| block.lines().collect())
+        .collect()
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
-        }
-    };
+"###;
 
-    let day_padded = format!("{day:02}");
+const BUILTIN_TEMPLATES: [&str; 4] = ["plain", "lines", "grid", "blocks"];
 
-    let input_path = format!("src/inputs/{day_padded}.txt");
-    let example_path = format!("src/examples/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
+struct Args {
+    day: u8,
+    /// The answer type of both parts.
+    answer_type: String,
+    template: String,
+    /// Also fetch the input and puzzle, and extract the examples of the puzzle.
+    download: bool,
+    year: Option<u16>,
+    /// Overwrite files of the day that already exist.
+    force: bool,
+}
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        day: args.free_from_str()?,
+        answer_type: args
+            .opt_value_from_str("--type")?
+            .unwrap_or_else(|| "i32".into()),
+        template: args
+            .opt_value_from_str("--template")?
+            .unwrap_or_else(|| "plain".into()),
+        download: args.contains("--download"),
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        force: args.contains("--force"),
     };
+    finish_args(args)?;
+    Ok(parsed)
+}
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+/// Where a template can be put to override a built-in one, or to add a new one.
+/// Like `MODULE_TEMPLATE`, it can use the placeholders `{{DAY}}` and `{{TYPE}}`.
+fn template_path(name: &str) -> PathBuf {
+    advent_of_code::root_dir()
+        .join("src")
+        .join("templates")
+        .join(format!("{name}.rs.template"))
+}
+
+/// Loads the template `name` from `src/templates`, falling back to the built-in ones.
+fn load_template(name: &str) -> Result<String, String> {
+    match fs::read_to_string(template_path(name)) {
+        Ok(template) => return Ok(template),
+        Err(e) if e.kind() != io::ErrorKind::NotFound => {
+            return Err(format!(
+                "could not read \"{}\": {e}",
+                template_path(name).display()
+            ))
         }
+        Err(_) => {}
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    let (parser, parse) = match name {
+        "plain" => ("", "let _ = input;"),
+        "lines" => (LINES_PARSER, "let _lines = parse_input(input);"),
+        "grid" => (GRID_PARSER, "let _grid = parse_grid(input);"),
+        "blocks" => (BLOCKS_PARSER, "let _blocks = parse_blocks(input);"),
+        _ => {
+            return Err(format!(
+                "unknown template \"{name}\". Use one of {} or add \"{}\".",
+                BUILTIN_TEMPLATES.join(", "),
+                template_path(name).display()
+            ))
         }
+    };
+
+    Ok(MODULE_TEMPLATE
+        .replace("{{PARSER}}", parser)
+        .replace("{{PARSE}}", parse))
+}
+
+fn render(template: &str, year: u16, day: u8, answer_type: &str) -> String {
+    let template = match year {
+        DEFAULT_YEAR => template.to_string(),
+        year => template
+            .replace(
+                "solution!({{DAY}}",
+                &format!("solution!({{{{DAY}}}}, year = {year}"),
+            )
+            .replace(
+                "example_tests!({{DAY}})",
+                &format!("example_tests!({{{{DAY}}}}, year = {year})"),
+            )
+            .replace(
+                "read_file(\"examples\", {{DAY}})",
+                &format!("read_year_file({year}, \"examples\", {{{{DAY}}}})"),
+            ),
+    };
+    template
+        .replace("{{TYPE}}", answer_type)
+        .replace("{{DAY}}", &day.to_string())
+}
+
+/// Adds `dayNN: "bin/NN.rs",` to the `register_days!` block of `src/days.rs`, keeping it sorted.
+/// Days of another year are added as `yYYYY_dayNN: "bin/YYYY-NN.rs",` after those of `DEFAULT_YEAR`.
+/// Returns `None` if the day is already registered.
+fn register_day(days: &str, year: u16, day: u8) -> Option<String> {
+    let module = match year {
+        DEFAULT_YEAR => format!("day{day:02}"),
+        year => format!("y{year}_day{day:02}"),
+    };
+    let entry = format!(
+        "    {module}: \"bin/{}.rs\",",
+        advent_of_code::bin_name(year, day)
+    );
+    if days.lines().any(|line| line.trim() == entry.trim()) {
+        return None;
+    }
+
+    let mut lines: Vec<&str> = days.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("register_days! {"))?;
+    let end = start + lines[start..].iter().position(|line| *line == "}")?;
+    let position = lines[start + 1..end]
+        .iter()
+        .position(|line| line.trim() > entry.trim())
+        .map_or(end, |index| start + 1 + index);
+
+    lines.insert(position, &entry);
+    Some(lines.join("\n") + "\n")
+}
+
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
+
+/// Creates an empty file at `path` for the user to fill in, unless there is one already.
+fn create_empty_file(path: &Path, name: &str) -> Result<(), String> {
+    if path.exists() {
+        return Ok(());
+    }
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, ""))
+        .map_err(|e| format!("Failed to create {name} file: {e}"))?;
+    println!("Created empty {name} file \"{}\"", path.display());
+    Ok(())
+}
+
+/// Fetches the input and puzzle of `day` and turns the examples of the puzzle into example files.
+fn download(year: u16, day: u8, force: bool) -> Result<(), String> {
+    let client = AocClient::from_env(Some(year)).map_err(|e| e.to_string())?;
+    let mut cache = InputCache::open_year(year).map_err(|e| e.to_string())?;
+
+    client
+        .download(day, &mut cache, force)
+        .map_err(|e| format!("Failed to download day {day}: {e}"))?;
+    println!("Downloaded input to \"{}\"", cache.path(day).display());
+    println!(
+        "Downloaded puzzle to \"{}\"",
+        get_puzzle_path(year, day).display()
+    );
+
+    let puzzle = fs::read_to_string(get_puzzle_path(year, day)).map_err(|e| e.to_string())?;
+    let examples = extract_examples(&puzzle);
+    if examples.is_empty() {
+        let path = advent_of_code::year_data_path(year, "examples", day);
+        create_empty_file(&path, "example")?;
+        println!(
+            "Found no examples in the puzzle, fill in \"{}\" by hand",
+            path.display()
+        );
+    }
+
+    let paths = write_examples(year, day, &examples, force)
+        .map_err(|e| format!("Failed to write examples: {e}"))?;
+    for path in paths {
+        println!("Created example file \"{}\"", path.display());
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => exit_with_error(
+            "Need to specify a day (as integer). example: `cargo scaffold 7 --type u64 --template grid --download`",
+        ),
+        Err(e) => exit_with_error(format!("Failed to process arguments: {e}")),
+    };
+    let (year, day) = (resolve_year(args.year), args.day);
+    let day_padded = format!("{day:02}");
+
+    let template = load_template(&args.template).unwrap_or_else(|e| exit_with_error(e));
+
+    let root = advent_of_code::root_dir();
+    let module_path = root
+        .join("src")
+        .join("bin")
+        .join(format!("{}.rs", advent_of_code::bin_name(year, day)));
+    let input_path = advent_of_code::year_data_path(year, "inputs", day);
+    let example_path = advent_of_code::year_data_path(year, "examples", day);
+    let days_path = root.join("src").join("days.rs");
+
+    let mut paths = vec![&module_path, &example_path];
+    if args.download {
+        paths.push(&input_path);
+    }
+    let existing: Vec<String> = paths
+        .iter()
+        .filter(|path| path.exists())
+        .map(|path| format!("\"{}\"", path.display()))
+        .collect();
+    if !existing.is_empty() && !args.force {
+        exit_with_error(format!(
+            "Refusing to scaffold day {day}, {} already exist. Pass `--force` to overwrite.",
+            existing.join(", ")
+        ));
+    }
+
+    // downloading comes first, so that a failed download leaves no files that block a retry.
+    if args.download {
+        download(year, day, args.force).unwrap_or_else(|e| exit_with_error(e));
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    if let Err(e) = fs::write(
+        &module_path,
+        render(&template, year, day, &args.answer_type),
+    ) {
+        exit_with_error(format!("Failed to write module file: {e}"));
+    }
+    println!("Created module file \"{}\"", module_path.display());
+
+    if !args.download {
+        for (path, name) in [(&input_path, "input"), (&example_path, "example")] {
+            create_empty_file(path, name).unwrap_or_else(|e| exit_with_error(e));
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    }
+
+    let days = fs::read_to_string(&days_path)
+        .unwrap_or_else(|e| exit_with_error(format!("Failed to read \"src/days.rs\": {e}")));
+    if let Some(days) = register_day(&days, year, day) {
+        if let Err(e) = fs::write(&days_path, days) {
+            exit_with_error(format!("Failed to register day in \"src/days.rs\": {e}"));
         }
+        println!("Registered day {day} of {year} in \"src/days.rs\"");
     }
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {day_padded}{}` to run your solution.",
+        advent_of_code::year_flag(year)
     );
+    println!("🎄 Type `cargo all` to run it together with all other days.");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let module = render(&load_template("grid").unwrap(), DEFAULT_YEAR, 7, "u64");
+
+        assert!(module.starts_with("use advent_of_code::helpers::Grid;"));
+        assert!(module.contains("{\n    let _grid = parse_grid(input);\n    None\n}"));
+        assert!(module.contains("pub fn part_one(input: &str) -> Option<u64> {"));
+        assert!(module.contains("advent_of_code::solution!(7);"));
+        assert!(module.contains("advent_of_code::read_file(\"examples\", 7);"));
+
+        let module = render(&load_template("plain").unwrap(), 2021, 7, "u64");
+
+        assert!(module.contains("advent_of_code::solution!(7, year = 2021);"));
+        assert!(module.contains("advent_of_code::example_tests!(7, year = 2021);"));
+        assert!(module.contains("advent_of_code::read_year_file(2021, \"examples\", 7);"));
+    }
+
+    #[test]
+    fn test_render_keeps_identifiers() {
+        let template = "const TODAY: u32 = {{DAY}};\nstruct PROTOTYPE(Vec<{{TYPE}}>);\nadvent_of_code::solution!({{DAY}});\n";
+        let module = render(template, 2021, 7, "u64");
+
+        assert_eq!(
+            module,
+            "const TODAY: u32 = 7;\nstruct PROTOTYPE(Vec<u64>);\nadvent_of_code::solution!(7, year = 2021);\n"
+        );
+    }
+
+    #[test]
+    fn test_unknown_template() {
+        assert!(load_template("hexagons").is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let days = "register_days! {\n    day01: \"bin/01.rs\",\n    day09: \"bin/09.rs\",\n}\n";
+
+        assert_eq!(
+            register_day(days, DEFAULT_YEAR, 7).unwrap(),
+            "register_days! {\n    day01: \"bin/01.rs\",\n    day07: \"bin/07.rs\",\n    day09: \"bin/09.rs\",\n}\n"
+        );
+        assert_eq!(
+            register_day(days, DEFAULT_YEAR, 12).unwrap(),
+            "register_days! {\n    day01: \"bin/01.rs\",\n    day09: \"bin/09.rs\",\n    day12: \"bin/12.rs\",\n}\n"
+        );
+        assert_eq!(register_day(days, DEFAULT_YEAR, 9), None);
+        assert_eq!(
+            register_day(days, 2021, 9).unwrap(),
+            "register_days! {\n    day01: \"bin/01.rs\",\n    day09: \"bin/09.rs\",\n    y2021_day09: \"bin/2021-09.rs\",\n}\n"
+        );
+    }
 }
@@ -0,0 +1,51 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::template::aoc_client::resolve_year;
+use std::{
+    env,
+    ffi::OsString,
+    process::{self, Command},
+};
+
+struct Args {
+    day: u8,
+    year: Option<u16>,
+    /// Passed on to the binary of the day, e.g. `--bench` or `--input`.
+    rest: Vec<OsString>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    Ok(Args {
+        day: args.free_from_str()?,
+        year,
+        rest: args.finish(),
+    })
+}
+
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
+
+/// Runs the binary of a day, `src/bin/NN.rs` or `src/bin/YYYY-NN.rs` if `--year` is given.
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(format!("Failed to process arguments: {e}")),
+    };
+    let bin = advent_of_code::bin_name(resolve_year(args.year), args.day);
+
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = Command::new(cargo)
+        .args(["run", "--release", "--bin", &bin, "--"])
+        .args(args.rest)
+        .current_dir(advent_of_code::root_dir())
+        .status()
+        .unwrap_or_else(|e| exit_with_error(format!("Failed to run day {bin}: {e}")));
+
+    process::exit(status.code().unwrap_or(1));
+}
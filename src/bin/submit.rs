@@ -0,0 +1,114 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::template::{
+    answers,
+    aoc_client::AocClient,
+    input_cache::read_input,
+    runner::{finish_args, RunOptions},
+    submissions::{SubmissionHistory, Verdict, DEFAULT_SUBMISSIONS_PATH},
+};
+use std::{
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+#[path = "../days.rs"]
+mod days;
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        year: args.opt_value_from_str(["-y", "--year"])?,
+    };
+    finish_args(args)?;
+    Ok(parsed)
+}
+
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is before the unix epoch")
+        .as_secs()
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(format!("Failed to process arguments: {e}")),
+    };
+    let (day, part) = (args.day, args.part);
+
+    if !(1..=2).contains(&part) {
+        exit_with_error(format!("a day only has two parts, got part {part}."));
+    }
+
+    let client = AocClient::from_env(args.year).unwrap_or_else(|e| exit_with_error(e));
+    let year = client.year();
+
+    let solution = days::SOLUTIONS
+        .iter()
+        .find(|solution| (solution.year(), solution.day()) == (year, day))
+        .unwrap_or_else(|| {
+            exit_with_error(format!(
+                "day {day:02} of {year} is not registered in \"src/days.rs\"."
+            ))
+        });
+
+    let input = read_input(year, day).unwrap_or_else(|e| exit_with_error(e));
+
+    let report = solution.solve(part, &input, &RunOptions::default());
+    if !report.solved() {
+        exit_with_error(format!("day {day:02} part {part} is {}.", report.status));
+    }
+    let answer = report.answer;
+
+    let history_path = &advent_of_code::root_dir().join(DEFAULT_SUBMISSIONS_PATH);
+    let mut history = SubmissionHistory::load_or_default(history_path).unwrap_or_else(|e| {
+        exit_with_error(format!(
+            "could not read \"{DEFAULT_SUBMISSIONS_PATH}\": {e}"
+        ))
+    });
+
+    if let Err(rejection) = history.check(year, day, part, &answer, now()) {
+        exit_with_error(format!("🎄 Not submitting \"{answer}\": {rejection}"));
+    }
+
+    println!("🎄 Submitting \"{answer}\" for day {day:02} part {part} of {year}...");
+    let response = client
+        .submit(day, part, &answer)
+        .unwrap_or_else(|e| exit_with_error(format!("failed to submit: {e}")));
+
+    history.record(year, day, part, &answer, &response, now());
+    if let Err(e) = history.save(history_path) {
+        exit_with_error(format!(
+            "could not write \"{DEFAULT_SUBMISSIONS_PATH}\": {e}"
+        ));
+    }
+
+    println!("---");
+    println!("{}", response.message);
+
+    match response.verdict {
+        Verdict::Correct => {
+            if let Err(e) = answers::save_answer(year, day, part, &answer) {
+                exit_with_error(format!("could not save answer: {e}"));
+            }
+        }
+        _ => process::exit(1),
+    }
+}
@@ -0,0 +1,51 @@
+/*
+ * This file contains the registry of solutions run by `cargo all`.
+ * Add a line for every day you create in `src/bin/`.
+ */
+use advent_of_code::Solution;
+
+macro_rules! register_days {
+    ($($module:ident: $path:literal),* $(,)?) => {
+        // the days are left out of test builds, their tests run in their own binaries.
+        $(
+            // days are linted as their own binaries, only `SOLUTION` is used here.
+            #[cfg(not(test))]
+            #[allow(dead_code)]
+            #[path = $path]
+            mod $module;
+        )*
+
+        #[cfg(not(test))]
+        pub static SOLUTIONS: &[&dyn Solution] = &[$($module::SOLUTION),*];
+        #[cfg(test)]
+        pub static SOLUTIONS: &[&dyn Solution] = &[];
+    };
+}
+
+register_days! {
+    day01: "bin/01.rs",
+    day02: "bin/02.rs",
+    day03: "bin/03.rs",
+    day04: "bin/04.rs",
+    day05: "bin/05.rs",
+    day06: "bin/06.rs",
+    day07: "bin/07.rs",
+    day08: "bin/08.rs",
+    day09: "bin/09.rs",
+    day10: "bin/10.rs",
+    day11: "bin/11.rs",
+    day12: "bin/12.rs",
+    day13: "bin/13.rs",
+    day14: "bin/14.rs",
+    day15: "bin/15.rs",
+    day16: "bin/16.rs",
+    day17: "bin/17.rs",
+    day18: "bin/18.rs",
+    day19: "bin/19.rs",
+    day20: "bin/20.rs",
+    day21: "bin/21.rs",
+    day22: "bin/22.rs",
+    day23: "bin/23.rs",
+    day24: "bin/24.rs",
+    day25: "bin/25.rs",
+}
@@ -2,3 +2,14 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+pub mod cycle;
+pub mod graph;
+pub mod grid;
+pub mod point;
+pub mod search;
+
+pub use cycle::{find_cycle, Cycle};
+pub use graph::{DistanceMatrix, Graph};
+pub use grid::Grid;
+pub use point::{Direction, Point2, Point3};
+pub use search::{astar, bfs, dijkstra, Path};
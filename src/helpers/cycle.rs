@@ -0,0 +1,112 @@
+use std::hash::Hash;
+
+use hashbrown::HashMap;
+use num::{FromPrimitive, Num};
+
+/// A simulation that repeats itself after `start` steps, every `period` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    /// The number of steps before the first repetition begins.
+    pub start: usize,
+    pub period: usize,
+    /// The states from the initial one up to the first repeated one, `start + period + 1` in total.
+    states: Vec<S>,
+}
+
+/// Runs `step` from `initial` until a state has the same `key` as an earlier one.
+/// The key leaves out what keeps growing, like the height of the tower on day 17,
+/// so that states which only differ in it count as the same. Never returns if nothing repeats.
+pub fn find_cycle<S, K>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle<S>
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![initial];
+
+    loop {
+        let index = states.len() - 1;
+        let state = &states[index];
+        if let Some(start) = seen.insert(key(state), index) {
+            return Cycle {
+                start,
+                period: index - start,
+                states,
+            };
+        }
+        let next = step(state);
+        states.push(next);
+    }
+}
+
+impl<S> Cycle<S> {
+    /// The step before `n` that the state after `n` steps is a repetition of.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        match n.checked_sub(self.start) {
+            Some(offset) => self.start + offset % self.period,
+            None => n,
+        }
+    }
+
+    /// The state after `n` steps, as far as `key` can tell.
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.equivalent_step(n)]
+    }
+
+    /// Extrapolates a `metric` that grows by the same amount every period, like the height of
+    /// the tower on day 17, to the value it has after `n` steps.
+    pub fn extrapolate<T>(&self, n: usize, metric: impl Fn(&S) -> T) -> T
+    where
+        T: Copy + Num + FromPrimitive,
+    {
+        let Some(offset) = n.checked_sub(self.start) else {
+            return metric(&self.states[n]);
+        };
+
+        let growth =
+            metric(&self.states[self.start + self.period]) - metric(&self.states[self.start]);
+        let periods = T::from_usize(offset / self.period).expect("too many periods for the metric");
+        metric(&self.states[self.start + offset % self.period]) + growth * periods
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Goes through 0, 1 once and then through 2, 3, 4 forever, summing up what it went through.
+    fn step(&(phase, total): &(u8, u64)) -> (u8, u64) {
+        let phase = if phase < 4 { phase + 1 } else { 2 };
+        (phase, total + u64::from(phase))
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle((0, 0), step, |&(phase, _)| phase);
+
+        assert_eq!((cycle.start, cycle.period), (2, 3));
+        assert_eq!(cycle.equivalent_step(1), 1);
+        assert_eq!(cycle.equivalent_step(5), 2);
+        assert_eq!(cycle.equivalent_step(9), 3);
+        assert_eq!(cycle.state_at(1_000_000).0, 4);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let cycle = find_cycle((0, 0), step, |&(phase, _)| phase);
+
+        let mut state = (0, 0);
+        for n in 0..50 {
+            assert_eq!(cycle.extrapolate(n, |&(_, total)| total), state.1);
+            state = step(&state);
+        }
+
+        assert_eq!(
+            cycle.extrapolate(1_000_000_000_000, |&(_, total)| total),
+            10 + 333_333_333_332 * 9
+        );
+    }
+}
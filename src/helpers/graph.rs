@@ -0,0 +1,298 @@
+use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash, ops::Add};
+
+use hashbrown::HashMap;
+use num::Zero;
+
+/// A directed graph with weighted edges between nodes of any hashable type.
+/// Nodes are numbered in the order they are added, which is what the distance matrices use.
+#[derive(Debug, Clone)]
+pub struct Graph<N, C> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, C)>>,
+}
+
+/// The distances between all pairs of some nodes of a graph, `None` if one can't reach the other.
+#[derive(Debug, Clone)]
+pub struct DistanceMatrix<N, C> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    /// Row-major, from the node of the row to the node of the column.
+    distances: Vec<Option<C>>,
+}
+
+impl<N, C> Default for Graph<N, C> {
+    fn default() -> Self {
+        Self {
+            nodes: vec![],
+            indices: HashMap::new(),
+            edges: vec![],
+        }
+    }
+}
+
+impl<N, C> Graph<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `node` unless it is part of the graph already, and returns its index either way.
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.edges.push(vec![]);
+        index
+    }
+
+    /// Adds an edge from `from` to `to`, adding both nodes if needed.
+    pub fn add_edge(&mut self, from: N, to: N, cost: C) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.edges[from].push((to, cost));
+    }
+
+    /// Adds an edge in both directions, like the tunnels between the valves of day 16.
+    pub fn add_undirected_edge(&mut self, a: N, b: N, cost: C) {
+        self.add_edge(a.clone(), b.clone(), cost);
+        self.add_edge(b, a, cost);
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn index(&self, node: &N) -> Option<usize> {
+        self.indices.get(node).copied()
+    }
+
+    pub fn node(&self, index: usize) -> &N {
+        &self.nodes[index]
+    }
+
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// The nodes that `index` has an edge to, with the cost of that edge.
+    pub fn edges(&self, index: usize) -> &[(usize, C)] {
+        &self.edges[index]
+    }
+
+    /// The distances from the node `start` to every node, by index.
+    pub fn distances_from(&self, start: usize) -> Vec<Option<C>> {
+        let mut distances = vec![None; self.len()];
+        let mut heap = BinaryHeap::from([Reverse((C::zero(), start))]);
+        distances[start] = Some(C::zero());
+
+        while let Some(Reverse((distance, index))) = heap.pop() {
+            if distances[index].is_some_and(|known| known < distance) {
+                continue;
+            }
+            for &(next, cost) in &self.edges[index] {
+                let distance = distance + cost;
+                if distances[next].is_none_or(|known| distance < known) {
+                    distances[next] = Some(distance);
+                    heap.push(Reverse((distance, next)));
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// The distances between all nodes, by Floyd–Warshall.
+    /// Fine for a few hundred nodes, use `contract` for larger sparse graphs.
+    pub fn all_distances(&self) -> DistanceMatrix<N, C> {
+        let size = self.len();
+        let mut distances = vec![None; size * size];
+
+        for (from, edges) in self.edges.iter().enumerate() {
+            distances[from * size + from] = Some(C::zero());
+            for &(to, cost) in edges {
+                let distance = &mut distances[from * size + to];
+                if distance.is_none_or(|known| cost < known) {
+                    *distance = Some(cost);
+                }
+            }
+        }
+
+        for via in 0..size {
+            for from in 0..size {
+                let Some(first) = distances[from * size + via] else {
+                    continue;
+                };
+                for to in 0..size {
+                    let Some(second) = distances[via * size + to] else {
+                        continue;
+                    };
+                    let distance = &mut distances[from * size + to];
+                    if distance.is_none_or(|known| first + second < known) {
+                        *distance = Some(first + second);
+                    }
+                }
+            }
+        }
+
+        DistanceMatrix::new(self.nodes.clone(), distances)
+    }
+
+    /// The distances between only the nodes that matter, e.g. the valves of day 16 that release
+    /// any pressure. Paths may still lead through the other nodes. Runs a search from every
+    /// kept node, which is much faster than `all_distances` if few nodes are kept.
+    pub fn contract(&self, mut keep: impl FnMut(&N) -> bool) -> DistanceMatrix<N, C> {
+        let kept: Vec<usize> = (0..self.len())
+            .filter(|&index| keep(&self.nodes[index]))
+            .collect();
+
+        let distances = kept
+            .iter()
+            .flat_map(|&from| {
+                let distances = self.distances_from(from);
+                kept.iter().map(move |&to| distances[to])
+            })
+            .collect();
+
+        let nodes = kept
+            .iter()
+            .map(|&index| self.nodes[index].clone())
+            .collect();
+        DistanceMatrix::new(nodes, distances)
+    }
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> DistanceMatrix<N, C> {
+    fn new(nodes: Vec<N>, distances: Vec<Option<C>>) -> Self {
+        let indices = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.clone(), index))
+            .collect();
+        Self {
+            nodes,
+            indices,
+            distances,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The index of `node` in this matrix, which differs from its index in the graph after `contract`.
+    pub fn index(&self, node: &N) -> Option<usize> {
+        self.indices.get(node).copied()
+    }
+
+    pub fn node(&self, index: usize) -> &N {
+        &self.nodes[index]
+    }
+
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// The distance between the nodes with the indices `from` and `to`.
+    pub fn get(&self, from: usize, to: usize) -> Option<C> {
+        self.distances[from * self.len() + to]
+    }
+
+    /// The distance between `from` and `to`, looked up by node.
+    pub fn distance(&self, from: &N, to: &N) -> Option<C> {
+        self.get(self.index(from)?, self.index(to)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The tunnels of the example of day 16.
+    fn tunnels() -> Graph<&'static str, u32> {
+        let mut graph = Graph::new();
+        for (valve, leads_to) in [
+            ("AA", ["DD", "II", "BB"].as_slice()),
+            ("BB", &["CC", "AA"]),
+            ("CC", &["DD", "BB"]),
+            ("DD", &["CC", "AA", "EE"]),
+            ("EE", &["FF", "DD"]),
+            ("FF", &["EE", "GG"]),
+            ("GG", &["FF", "HH"]),
+            ("HH", &["GG"]),
+            ("II", &["AA", "JJ"]),
+            ("JJ", &["II"]),
+        ] {
+            for to in leads_to {
+                graph.add_edge(valve, to, 1);
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn test_graph() {
+        let graph = tunnels();
+
+        assert_eq!(graph.len(), 10);
+        assert_eq!(graph.index(&"AA"), Some(0));
+        assert_eq!(graph.node(3), &"BB");
+        assert_eq!(graph.edges(0).len(), 3);
+
+        let distances = graph.distances_from(0);
+        assert_eq!(distances[graph.index(&"HH").unwrap()], Some(5));
+        assert_eq!(distances[graph.index(&"JJ").unwrap()], Some(2));
+    }
+
+    #[test]
+    fn test_all_distances_and_contract() {
+        let graph = tunnels();
+        let all = graph.all_distances();
+
+        assert_eq!(all.len(), 10);
+        assert_eq!(all.distance(&"JJ", &"HH"), Some(7));
+        assert_eq!(all.distance(&"CC", &"CC"), Some(0));
+
+        let flowing = ["BB", "CC", "DD", "EE", "HH", "JJ"];
+        let contracted = graph.contract(|valve| *valve == "AA" || flowing.contains(valve));
+
+        assert_eq!(
+            contracted.nodes(),
+            &["AA", "DD", "BB", "CC", "EE", "HH", "JJ"]
+        );
+        assert_eq!(contracted.distance(&"JJ", &"HH"), Some(7));
+        assert_eq!(contracted.get(0, 3), Some(2));
+        assert_eq!(contracted.distance(&"AA", &"GG"), None);
+
+        for from in flowing {
+            for to in flowing {
+                assert_eq!(contracted.distance(&from, &to), all.distance(&from, &to));
+            }
+        }
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let mut graph = Graph::new();
+        graph.add_edge('a', 'b', 3);
+        graph.add_node('c');
+
+        let all = graph.all_distances();
+        assert_eq!(all.distance(&'a', &'b'), Some(3));
+        assert_eq!(all.distance(&'b', &'a'), None);
+        assert_eq!(all.distance(&'a', &'c'), None);
+    }
+}
@@ -0,0 +1,353 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use super::Point2;
+
+/// The offsets of the horizontal and vertical neighbours of a cell, clockwise from the top.
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets of all eight neighbours of a cell, clockwise from the top.
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular map of cells, indexed by `(x, y)` with `(0, 0)` in the top left corner.
+/// `x` grows to the right and `y` downwards, like the lines of a puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Reads a map of characters, one row per line.
+    pub fn parse(input: &str) -> Self {
+        Self::parse_with(input, |cell| cell)
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` × `height` cells that are all `cell`.
+    pub fn new(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    /// A grid of `width` columns from cells in row-major order.
+    /// Panics if the cells don't fill the last row.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't make rows of {width}",
+            cells.len()
+        );
+        Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Reads a map of characters, one row per line, converting every character with `cell`.
+    /// Panics if the lines differ in length.
+    pub fn parse_with(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            assert_eq!(
+                cells.len() - len,
+                width,
+                "line {y} of the grid has a different length than the first one"
+            );
+        }
+
+        Self {
+            width,
+            height: input.lines().count(),
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(x, y)` lies on the grid.
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y)
+    }
+
+    /// The cell at `(x, y)`, or `None` if that lies off the grid.
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.contains(x, y)
+            .then(|| &self.cells[y as usize * self.width + x as usize])
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.contains(x, y)
+            .then(|| &mut self.cells[y as usize * self.width + x as usize])
+    }
+
+    /// Like `get`, for points computed with signed offsets, e.g. `point + Direction::Up`.
+    pub fn get_point(&self, point: Point2<isize>) -> Option<&T> {
+        self.get(point.x, point.y)
+    }
+
+    /// The cell at `(x, y)` on a grid that repeats in every direction.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[(x, y)]
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell that matches `predicate`, e.g. the start of a maze.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Panics if `x` lies off the grid, like indexing does.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "x {x} is off a grid of width {}",
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// The positions next to `(x, y)` horizontally and vertically that lie on the grid.
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Like `neighbours`, including the diagonal ones.
+    pub fn neighbours_diagonal(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &NEIGHBOURS_8)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (x, y) = (x as isize + dx, y as isize + dy);
+            self.contains(x, y).then_some((x as usize, y as usize))
+        })
+    }
+
+    /// The positions from `(x, y)` in steps of `(dx, dy)` up to the edge of the grid,
+    /// excluding `(x, y)` itself. E.g. the trees that can be seen from a tree on day 8.
+    pub fn ray(
+        &self,
+        x: usize,
+        y: usize,
+        dx: isize,
+        dy: isize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        assert!((dx, dy) != (0, 0), "a ray needs a direction");
+        (1..)
+            .map(move |step| (x as isize + dx * step, y as isize + dy * step))
+            .take_while(|&(x, y)| self.contains(x, y))
+            .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    /// A grid of the same size with every cell converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    /// Panics if `(x, y)` lies off the grid, use `get` to check first.
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width,
+            "x {x} is off a grid of width {}",
+            self.width
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width,
+            "x {x} is off a grid of width {}",
+            self.width
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+/// Prints the grid like the puzzle shows it, which helps debugging.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TREES: &str = "30373\n25512\n65332\n33549\n35390\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(TREES);
+
+        assert_eq!((grid.width(), grid.height()), (5, 5));
+        assert_eq!(grid[(1, 0)], '0');
+        assert_eq!(grid.get(4, 3), Some(&'9'));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(5, 0), None);
+        assert_eq!(grid[Point2::new(1, 0)], '0');
+        assert_eq!(grid.get_point(Point2::new(4, 3)), Some(&'9'));
+        assert_eq!(grid.to_string(), TREES);
+
+        let heights = Grid::parse_with(TREES, |cell| cell.to_digit(10).unwrap());
+        assert_eq!(heights.position(|height| *height == 9), Some((4, 3)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_ragged() {
+        Grid::parse("###\n#\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_column_off_grid() {
+        Grid::parse(TREES).column(7).count();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_column_of_empty_grid() {
+        Grid::<char>::parse("").column(0).count();
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = Grid::from_cells(2, vec![1, 2, 3, 4]);
+
+        assert_eq!(*grid.get_wrapping(-1, 0), 2);
+        assert_eq!(*grid.get_wrapping(2, 3), 3);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, '.');
+
+        assert_eq!(
+            grid.neighbours(1, 1).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 1), (1, 2), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_diagonal(1, 1).count(), 8);
+        assert_eq!(grid.neighbours_diagonal(2, 2).count(), 3);
+    }
+
+    #[test]
+    fn test_rows_columns_and_rays() {
+        let grid = Grid::parse(TREES);
+
+        assert_eq!(grid.row(1), &['2', '5', '5', '1', '2']);
+        assert_eq!(grid.rows().count(), 5);
+        assert_eq!(grid.column(2).collect::<String>(), "35353");
+        assert_eq!(
+            grid.ray(2, 3, 0, -1)
+                .map(|position| grid[position])
+                .collect::<String>(),
+            "353"
+        );
+        assert_eq!(grid.ray(4, 4, 1, 1).count(), 0);
+        assert_eq!(
+            grid.map(|cell| *cell == '3')
+                .iter()
+                .filter(|(_, three)| **three)
+                .count(),
+            9
+        );
+    }
+}
@@ -0,0 +1,412 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num::{One, Signed, Zero};
+
+/// A position or offset on a plane. Like on a `Grid`, `y` grows downwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A position or offset in space, e.g. a cube of a droplet on day 18.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// One of the four directions on a plane, with `Up` towards smaller `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + Signed> Point2<T> {
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        let distance = (self - other).abs();
+        distance.x + distance.y
+    }
+
+    /// The distance if diagonal steps are allowed, like the tail following the head on day 9.
+    pub fn chebyshev(self, other: Self) -> T
+    where
+        T: Ord,
+    {
+        let distance = (self - other).abs();
+        distance.x.max(distance.y)
+    }
+
+    /// The same offset turned by 90° counterclockwise, as seen on screen.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// The same offset turned by 90° clockwise, as seen on screen.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// The four points next to this one, in the order of `Direction::ALL`.
+    pub fn neighbours(self) -> [Self; 4] {
+        Direction::ALL.map(|direction| self + direction)
+    }
+
+    /// The eight points around this one, clockwise from the one above.
+    pub fn neighbours_diagonal(self) -> [Self; 8] {
+        let (zero, one) = (T::zero(), T::one());
+        [
+            (zero, -one),
+            (one, -one),
+            (one, zero),
+            (one, one),
+            (zero, one),
+            (-one, one),
+            (-one, zero),
+            (-one, -one),
+        ]
+        .map(|(x, y)| self + Self::new(x, y))
+    }
+}
+
+impl<T: Ord> Point2<T> {
+    /// The smaller coordinates of both points, e.g. to find the corner of a bounding box.
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Copy + Signed> Point3<T> {
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        let distance = (self - other).abs();
+        distance.x + distance.y + distance.z
+    }
+
+    pub fn chebyshev(self, other: Self) -> T
+    where
+        T: Ord,
+    {
+        let distance = (self - other).abs();
+        distance.x.max(distance.y).max(distance.z)
+    }
+
+    /// The six points that share a face with this one.
+    pub fn neighbours(self) -> [Self; 6] {
+        let (zero, one) = (T::zero(), T::one());
+        [
+            (-one, zero, zero),
+            (one, zero, zero),
+            (zero, -one, zero),
+            (zero, one, zero),
+            (zero, zero, -one),
+            (zero, zero, one),
+        ]
+        .map(|(x, y, z)| self + Self::new(x, y, z))
+    }
+}
+
+impl<T: Ord> Point3<T> {
+    pub fn min(self, other: Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+impl Direction {
+    /// Clockwise, starting upwards.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The step of length one in this direction.
+    pub fn offset<T: Zero + One + Neg<Output = T>>(self) -> Point2<T> {
+        match self {
+            Direction::Up => Point2::new(T::zero(), -T::one()),
+            Direction::Right => Point2::new(T::one(), T::zero()),
+            Direction::Down => Point2::new(T::zero(), T::one()),
+            Direction::Left => Point2::new(-T::one(), T::zero()),
+        }
+    }
+}
+
+/// Reads the arrows of a map like `^>v<` or the letters of instructions like `URDL`.
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            'v' | 'D' | 'S' => Ok(Direction::Down),
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            _ => Err(format!("\"{value}\" is not a direction")),
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Zero + One + Neg<Output = T>> Add<Direction> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Copy + Zero + One + Neg<Output = T>> AddAssign<Direction> for Point2<T> {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Point3<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(point: Point3<T>) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut point = Point2::new(3, -2);
+
+        assert_eq!(point + Point2::new(1, 1), Point2::new(4, -1));
+        assert_eq!(point - Point2::new(1, 1), Point2::new(2, -3));
+        assert_eq!(-point, Point2::new(-3, 2));
+        assert_eq!(point * 3, Point2::new(9, -6));
+        assert_eq!(point.abs(), Point2::new(3, 2));
+        assert_eq!(point.min(Point2::new(0, 5)), Point2::new(0, -2));
+        assert_eq!(point.max(Point2::new(0, 5)), Point2::new(3, 5));
+
+        point += Direction::Up;
+        point -= Point2::new(3, 0);
+        assert_eq!(point, Point2::new(0, -3));
+        assert_eq!(<(i32, i32)>::from(point), (0, -3));
+
+        assert_eq!(
+            Point3::new(1, 2, 3) - Point3::from((1, 1, 1)),
+            Point3::new(0, 1, 2)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point2::new(1, 1), Point2::new(4, -1));
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+
+        let (a, b) = (Point3::new(1, 1, 1), Point3::new(2, -1, 5));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point2::new(0, 0);
+
+        assert_eq!(
+            point.neighbours(),
+            [
+                Point2::new(0, -1),
+                Point2::new(1, 0),
+                Point2::new(0, 1),
+                Point2::new(-1, 0)
+            ]
+        );
+        assert!(point
+            .neighbours_diagonal()
+            .iter()
+            .all(|neighbour| neighbour.chebyshev(point) == 1));
+        assert!(Point3::new(0, 0, 0)
+            .neighbours()
+            .iter()
+            .all(|neighbour| neighbour.manhattan(Point3::default()) == 1));
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+        assert!(Direction::try_from('x').is_err());
+
+        for direction in Direction::ALL {
+            let offset: Point2<i32> = direction.offset();
+            assert_eq!(offset.rotate_right(), direction.turn_right().offset());
+            assert_eq!(offset.rotate_left(), direction.turn_left().offset());
+        }
+    }
+}
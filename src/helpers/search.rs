@@ -0,0 +1,271 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use hashbrown::HashMap;
+use num::Zero;
+
+/// A cheapest way from one of the starts of a search to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// From the start to the goal, both included.
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn start(&self) -> &S {
+        &self.states[0]
+    }
+
+    pub fn goal(&self) -> &S {
+        &self.states[self.states.len() - 1]
+    }
+}
+
+/// The states a search has reached, with the cheapest known way to each of them.
+/// States are numbered in the order they are reached, so that they need no `Ord` for the heap.
+struct Visited<S, C> {
+    states: Vec<S>,
+    indices: HashMap<S, usize>,
+    /// The index of the state each state was reached from, `None` for the starts.
+    parents: Vec<Option<usize>>,
+    costs: Vec<C>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + PartialOrd> Visited<S, C> {
+    fn new() -> Self {
+        Self {
+            states: vec![],
+            indices: HashMap::new(),
+            parents: vec![],
+            costs: vec![],
+        }
+    }
+
+    /// Records that `state` can be reached for `cost` and returns its index,
+    /// unless it was already reached as cheaply.
+    fn reach(&mut self, state: S, parent: Option<usize>, cost: C) -> Option<usize> {
+        match self.indices.get(&state) {
+            Some(&index) if self.costs[index] <= cost => None,
+            Some(&index) => {
+                self.parents[index] = parent;
+                self.costs[index] = cost;
+                Some(index)
+            }
+            None => {
+                let index = self.states.len();
+                self.indices.insert(state.clone(), index);
+                self.states.push(state);
+                self.parents.push(parent);
+                self.costs.push(cost);
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<S, C> {
+        let mut states = vec![];
+        let mut index = Some(goal);
+        while let Some(current) = index {
+            states.push(self.states[current].clone());
+            index = self.parents[current];
+        }
+        states.reverse();
+
+        Path {
+            states,
+            cost: self.costs[goal],
+        }
+    }
+}
+
+/// Finds a path with the fewest steps from any of `starts` to a state that satisfies `is_goal`.
+/// `successors` lists the states one step away from a state.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new();
+    let mut queue: VecDeque<usize> = starts
+        .into_iter()
+        .filter_map(|start| visited.reach(start, None, 0))
+        .collect();
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&visited.states[index]) {
+            return Some(visited.path(index));
+        }
+
+        let cost = visited.costs[index] + 1;
+        for successor in successors(&visited.states[index]) {
+            queue.extend(visited.reach(successor, Some(index), cost));
+        }
+    }
+
+    None
+}
+
+/// Finds a cheapest path from any of `starts` to a state that satisfies `is_goal`.
+/// `successors` lists the states one step away from a state, together with the cost of that step.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::zero(), is_goal)
+}
+
+/// Like `dijkstra`, guided by `heuristic`, which estimates the remaining cost from a state.
+/// The path is only guaranteed to be cheapest if `heuristic` never overestimates,
+/// e.g. the Manhattan distance to the goal on a map where every step costs at least one.
+///
+/// States that change over time, like the blizzards of day 24, can be searched by making the
+/// time, or the time modulo the period of the changes, part of the state.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if let Some(index) = visited.reach(start, None, C::zero()) {
+            let estimate = heuristic(&visited.states[index]);
+            heap.push(Reverse((estimate, C::zero(), index)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        // the state was reached more cheaply after this entry was pushed.
+        if cost > visited.costs[index] {
+            continue;
+        }
+        if is_goal(&visited.states[index]) {
+            return Some(visited.path(index));
+        }
+
+        for (successor, step) in successors(&visited.states[index]) {
+            let cost = cost + step;
+            if let Some(successor) = visited.reach(successor, Some(index), cost) {
+                let estimate = cost + heuristic(&visited.states[successor]);
+                heap.push(Reverse((estimate, cost, successor)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{Grid, Point2};
+
+    const MAZE: &str = "S.#.....\n..#.###.\n....#E..\n";
+
+    fn open_neighbours(maze: &Grid<char>, position: Point2<usize>) -> Vec<Point2<usize>> {
+        maze.neighbours(position.x, position.y)
+            .filter(|&neighbour| maze[neighbour] != '#')
+            .map(Point2::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = Grid::parse(MAZE);
+        let start = Point2::from(maze.position(|cell| *cell == 'S').unwrap());
+
+        let path = bfs(
+            [start],
+            |&position| open_neighbours(&maze, position),
+            |&position| maze[position] == 'E',
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.states.len(), 16);
+        assert_eq!(*path.start(), start);
+        assert_eq!(*path.goal(), Point2::new(5, 2));
+        assert!(path
+            .states
+            .windows(2)
+            .all(|step| open_neighbours(&maze, step[0]).contains(&step[1])));
+
+        assert_eq!(bfs([start], |_| vec![], |_| false), None);
+    }
+
+    #[test]
+    fn test_multiple_starts() {
+        let maze = Grid::parse(MAZE);
+        let starts = maze
+            .iter()
+            .filter(|(_, cell)| **cell == '.')
+            .map(|(position, _)| Point2::from(position));
+
+        let path = bfs(
+            starts,
+            |&position| open_neighbours(&maze, position),
+            |&position| maze[position] == 'E',
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 1);
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // the direct edge is more expensive than the detour.
+        let edges = |node: &u8| match node {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            _ => vec![],
+        };
+
+        let path = dijkstra([0], edges, |node| *node == 3).unwrap();
+        assert_eq!(path.states, vec![0, 1, 2, 3]);
+        assert_eq!(path.cost, 6);
+
+        let estimates = |node: &u8| 3 - (*node).min(3) as u32;
+        let path = astar([0], edges, estimates, |node| *node == 3).unwrap();
+        assert_eq!(path.cost, 6);
+    }
+
+    #[test]
+    fn test_time_dependent_states() {
+        // a gate at position 3 is only open every fourth minute, waiting in place is allowed.
+        let successors = |&(position, minute): &(u8, u32)| {
+            [position, position + 1]
+                .into_iter()
+                .filter(move |&next| next != 3 || (minute + 1) % 4 == 0)
+                .map(move |next| ((next, minute + 1), 1))
+                .collect::<Vec<_>>()
+        };
+
+        let path = dijkstra([(0, 0)], successors, |&(position, _)| position == 5).unwrap();
+
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states[4], (3, 4));
+    }
+}
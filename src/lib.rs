@@ -4,9 +4,17 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
+use std::fmt::Display;
 use std::fs;
+use std::io;
+use std::path::PathBuf;
 
 pub mod helpers;
+pub mod template;
+
+pub use template::examples::{read_example, read_year_example};
+pub use template::report::SolveReport;
+pub use template::solution::{Day, Solution};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -14,220 +22,209 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
+    ($day:expr, $part:expr, $solver:ident, $input:expr) => {{
+        advent_of_code::solve!($day, $part, $solver, $input, &Default::default())
+    }};
+    ($day:expr, $part:expr, $solver:ident, $input:expr, $options:expr) => {{
+        use advent_of_code::template::{report::TextReporter, runner::run_part};
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        let mut reporter = TextReporter::new(std::io::stdout());
+        run_part(&mut reporter, $day, $part, $solver, $input, $options)
+            .expect("could not write to stdout")
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-
-    let filepath = cwd.join("src").join(folder).join(format!("{day:02}.txt"));
-
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
-}
-
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
-}
-
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
-    })
+/// The year of the days in `src/bin/NN.rs`. Days of other years live in `src/bin/YYYY-NN.rs`.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// Overrides the directory that `src/inputs`, `src/examples` etc. are looked up in.
+pub const ROOT_ENV: &str = "AOC_ROOT";
+
+#[derive(Debug)]
+pub enum ReadError {
+    Missing {
+        day: u8,
+        path: PathBuf,
+    },
+    Empty {
+        day: u8,
+        path: PathBuf,
+    },
+    Unreadable {
+        day: u8,
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// The file has the same contents as the one of another day.
+    WrongDay {
+        day: u8,
+        path: PathBuf,
+        actual: u8,
+    },
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+impl ReadError {
+    pub fn day(&self) -> u8 {
+        match self {
+            ReadError::Missing { day, .. }
+            | ReadError::Empty { day, .. }
+            | ReadError::Unreadable { day, .. }
+            | ReadError::WrongDay { day, .. } => *day,
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){ANSI_RESET}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){ANSI_RESET}"
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Missing { day, path } => {
+                write!(
+                    f,
+                    "file of day {day:02} is missing at \"{}\".",
+                    path.display()
+                )
+            }
+            ReadError::Empty { day, path } => {
+                write!(
+                    f,
+                    "file of day {day:02} at \"{}\" is empty.",
+                    path.display()
+                )
+            }
+            ReadError::Unreadable { day, path, source } => write!(
+                f,
+                "file of day {day:02} at \"{}\" could not be read: {source}",
+                path.display()
             ),
-            10400.50_f64
-        );
+            ReadError::WrongDay { day, path, actual } => write!(
+                f,
+                "file of day {day:02} at \"{}\" is the one of day {actual:02}.",
+                path.display()
+            ),
+        }
     }
 }
 
-pub mod aoc_cli {
-    use std::{
-        fmt::Display,
-        fs::create_dir_all,
-        process::{Command, Output, Stdio},
-    };
+impl std::error::Error for ReadError {}
 
-    pub enum AocCliError {
-        CommandNotFound,
-        CommandNotCallable,
-        BadExitStatus(Output),
-        IoError,
-    }
+/// The directory containing `src/`, so that binaries work from anywhere.
+/// This is the directory of `Cargo.toml` unless `AOC_ROOT` is set.
+pub fn root_dir() -> PathBuf {
+    env::var_os(ROOT_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+}
 
-    impl Display for AocCliError {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                AocCliError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-                AocCliError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-                AocCliError::BadExitStatus(_) => {
-                    write!(f, "aoc-cli exited with a non-zero status.")
-                }
-                AocCliError::IoError => write!(f, "could not write output files to file system."),
-            }
-        }
+/// The directory that the inputs, examples, answers and puzzles of `year` live in.
+/// That is `src/` for `DEFAULT_YEAR` and `src/{year}/` for every other year.
+pub fn year_dir(year: u16) -> PathBuf {
+    let src = root_dir().join("src");
+    match year {
+        DEFAULT_YEAR => src,
+        year => src.join(year.to_string()),
     }
+}
 
-    pub fn check() -> Result<(), AocCliError> {
-        Command::new("aoc")
-            .arg("-V")
-            .output()
-            .map_err(|_| AocCliError::CommandNotFound)?;
-        Ok(())
+/// The name of the binary of a day: `NN` for `DEFAULT_YEAR`, `YYYY-NN` for every other year.
+pub fn bin_name(year: u16, day: u8) -> String {
+    match year {
+        DEFAULT_YEAR => format!("{day:02}"),
+        year => format!("{year}-{day:02}"),
     }
+}
 
-    pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
-        let args = build_args("read", &[], day, year);
-        call_aoc_cli(&args)
+/// The `--year` to pass along to other commands, e.g. ` --year 2021`, empty for `DEFAULT_YEAR`.
+pub fn year_flag(year: u16) -> String {
+    match year {
+        DEFAULT_YEAR => String::new(),
+        year => format!(" --year {year}"),
     }
+}
 
-    pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
-
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
-
-        let args = build_args(
-            "download",
-            &[
-                "--overwrite".into(),
-                "--input-file".into(),
-                input_path.to_string(),
-                "--puzzle-file".into(),
-                puzzle_path.to_string(),
-            ],
-            day,
-            year,
-        );
-
-        let output = call_aoc_cli(&args)?;
-
-        if output.status.success() {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-            Ok(output)
-        } else {
-            Err(AocCliError::BadExitStatus(output))
-        }
-    }
+/// The path of the file of `day` of `DEFAULT_YEAR` in `src/{folder}`.
+pub fn data_path(folder: &str, day: u8) -> PathBuf {
+    year_data_path(DEFAULT_YEAR, folder, day)
+}
 
-    fn get_input_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/inputs/{day_padded}.txt")
-    }
+/// The path of the file of `day` of `year` in `{folder}`, see `year_dir`.
+pub fn year_data_path(year: u16, folder: &str, day: u8) -> PathBuf {
+    year_dir(year).join(folder).join(format!("{day:02}.txt"))
+}
 
-    fn get_puzzle_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/puzzles/{day_padded}.md")
+/// Like `try_read_file`, but panics with the reason if the file can't be used.
+/// An empty file, like the example of a freshly scaffolded day, reads as `""`.
+pub fn read_file(folder: &str, day: u8) -> String {
+    read_year_file(DEFAULT_YEAR, folder, day)
+}
+
+pub fn try_read_file(folder: &str, day: u8) -> Result<String, ReadError> {
+    try_read_year_file(DEFAULT_YEAR, folder, day)
+}
+
+/// Like `read_file`, for days of another year than `DEFAULT_YEAR`.
+pub fn read_year_file(year: u16, folder: &str, day: u8) -> String {
+    contents_or_panic(try_read_year_file(year, folder, day))
+}
+
+fn contents_or_panic(result: Result<String, ReadError>) -> String {
+    match result {
+        Ok(contents) => contents,
+        Err(ReadError::Empty { .. }) => String::new(),
+        Err(e) => panic!("{e}"),
     }
+}
 
-    fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
-        let mut cmd_args = args.to_vec();
+pub fn try_read_year_file(year: u16, folder: &str, day: u8) -> Result<String, ReadError> {
+    try_read_path(day, year_data_path(year, folder, day))
+}
 
-        if let Some(year) = year {
-            cmd_args.push("--year".into());
-            cmd_args.push(year.to_string());
-        }
+/// Reads a file of `day` from anywhere, e.g. an input passed with `--input`.
+pub fn try_read_path(day: u8, path: impl Into<PathBuf>) -> Result<String, ReadError> {
+    let path = path.into();
 
-        cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    match fs::read_to_string(&path) {
+        Ok(contents) if contents.is_empty() => Err(ReadError::Empty { day, path }),
+        Ok(contents) => Ok(contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ReadError::Missing { day, path }),
+        Err(source) => Err(ReadError::Unreadable { day, path, source }),
+    }
+}
 
-        cmd_args
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_read_file() {
+        assert!(try_read_file("examples", 1).unwrap().starts_with("1000\n"));
+        assert!(matches!(
+            try_read_file("examples", 26),
+            Err(ReadError::Missing { day: 26, .. })
+        ));
+        assert!(matches!(
+            try_read_year_file(2015, "examples", 1),
+            Err(ReadError::Missing { day: 1, .. })
+        ));
     }
 
-    fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
-        if cfg!(debug_assertions) {
-            println!("Calling >aoc with: {}", args.join(" "));
-        }
+    #[test]
+    fn test_read_empty_file() {
+        let path = env::temp_dir().join(format!("aoc-empty-{}.txt", std::process::id()));
+        fs::write(&path, "").unwrap();
+        let result = try_read_path(7, &path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(ReadError::Empty { day: 7, .. })));
+        assert_eq!(contents_or_panic(result), "");
+    }
 
-        Command::new("aoc")
-            .args(args)
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .output()
-            .map_err(|_| AocCliError::CommandNotCallable)
+    #[test]
+    fn test_year_data_path() {
+        assert!(year_data_path(DEFAULT_YEAR, "inputs", 5).ends_with("src/inputs/05.txt"));
+        assert!(year_data_path(2021, "inputs", 5).ends_with("src/2021/inputs/05.txt"));
+        assert_eq!(bin_name(DEFAULT_YEAR, 5), "05");
+        assert_eq!(bin_name(2021, 5), "2021-05");
+        assert_eq!(year_flag(DEFAULT_YEAR), "");
+        assert_eq!(year_flag(2021), " --year 2021");
     }
 }
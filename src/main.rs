@@ -2,44 +2,197 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::{
+    template::{
+        input_cache::{read_input, InputError, InputSource},
+        report::{Format, Reporter},
+        runner::{finish_args, has_wrong_answers, report_part, RunOptions},
+        selection::Selection,
+    },
+    Solution, SolveReport,
+};
+use rayon::prelude::*;
+use std::{io, process, time::Duration};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+mod days;
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+/// Used unless `--timeout` is given, so that a hanging day doesn't stall the whole run.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+struct Args {
+    format: Format,
+    selection: Selection,
+    /// Run days concurrently instead of one after the other, at the cost of accurate timings.
+    /// `--sequential` runs them one after the other, which is also the default.
+    parallel: bool,
+    options: RunOptions,
+}
 
-            println!("----------");
-            println!("{ANSI_BOLD}| Day {day} |{ANSI_RESET}");
-            println!("----------");
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        format: args.opt_value_from_str("--format")?.unwrap_or(Format::Text),
+        selection: Selection::from_args(&mut args)?,
+        parallel: match (args.contains("--parallel"), args.contains("--sequential")) {
+            (true, true) => {
+                return Err(pico_args::Error::Utf8ArgumentParsingFailed {
+                    value: "--parallel --sequential".into(),
+                    cause: "pick one of them".into(),
+                })
+            }
+            (parallel, _) => parallel,
+        },
+        options: RunOptions::from_args(&mut args)?,
+    };
+    finish_args(args)?;
+    Ok(parsed)
+}
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+/// Solves the selected parts of a day and reports each of them as soon as it is done.
+fn run_day(
+    solution: &dyn Solution,
+    parts: &[u8],
+    reporter: &mut dyn Reporter,
+    options: &RunOptions,
+) -> io::Result<Vec<SolveReport>> {
+    let (year, day) = (solution.year(), solution.day());
+    reporter.day_started(year, day)?;
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
+    let input = match read_input(year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            reporter.day_skipped(year, day, parts)?;
+            return Ok(vec![]);
+        }
+    };
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
-            }
+    parts
+        .iter()
+        .map(|&part| {
+            report_part(reporter, day, part, options, || {
+                solution.solve(part, &input, options)
+            })
         })
-        .sum();
+        .collect()
+}
+
+/// Solves the selected parts of a day without reporting.
+fn solve_day(
+    solution: &dyn Solution,
+    parts: &[u8],
+    options: &RunOptions,
+) -> Result<Vec<SolveReport>, InputError> {
+    let input = read_input(solution.year(), solution.day())?;
+
+    Ok(parts
+        .iter()
+        .map(|&part| solution.solve(part, &input, options))
+        .collect())
+}
+
+/// Reports a day that was solved by `solve_day`.
+fn report_day(
+    solution: &dyn Solution,
+    parts: &[u8],
+    reports: Result<Vec<SolveReport>, InputError>,
+    reporter: &mut dyn Reporter,
+    options: &RunOptions,
+) -> io::Result<Vec<SolveReport>> {
+    let (year, day) = (solution.year(), solution.day());
+    reporter.day_started(year, day)?;
+
+    match reports {
+        Ok(reports) => reports
+            .into_iter()
+            .map(|report| report_part(reporter, day, report.part, options, || report))
+            .collect(),
+        Err(e) => {
+            eprintln!("{e}");
+            reporter.day_skipped(year, day, parts)?;
+            Ok(vec![])
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    if args.options.input != InputSource::Cache {
+        eprintln!("Failed to process arguments: `--input` only works with `cargo solve`.");
+        process::exit(1);
+    }
+
+    args.options.timeout.get_or_insert(DEFAULT_TIMEOUT);
+    let mut reporter = args.format.reporter(io::stdout());
+
+    let mut reports = vec![];
+    let parts = args.selection.parts();
+    let mut solutions: Vec<&dyn Solution> = days::SOLUTIONS
+        .iter()
+        .copied()
+        .filter(|solution| args.selection.includes(*solution))
+        .collect();
+    solutions.sort_by_key(|solution| (solution.year(), solution.day()));
+
+    // years are only announced if there is more than one, so that their totals add up.
+    let several_years = solutions
+        .iter()
+        .any(|solution| solution.year() != solutions[0].year());
+    let mut year = None;
+    let mut start_year = |reporter: &mut dyn Reporter, solution: &dyn Solution| {
+        if several_years && year.replace(solution.year()) != Some(solution.year()) {
+            reporter.year_started(solution.year())?;
+        }
+        io::Result::Ok(())
+    };
+
+    // benchmarks and baselines need days that don't compete for cores.
+    let timings_matter = args.options.bench_runs.is_some()
+        || args.options.baseline.save
+        || args.options.baseline.compare;
+    if args.parallel && timings_matter {
+        eprintln!(
+            "Ignoring `--parallel`, timings are only accurate if days run one after the other."
+        );
+    }
+
+    if !args.parallel || timings_matter {
+        for solution in solutions {
+            start_year(reporter.as_mut(), solution)?;
+            reports.extend(run_day(solution, &parts, reporter.as_mut(), &args.options)?);
+        }
+    } else {
+        // days run concurrently, but are reported in order once all of them are done.
+        let solved: Vec<_> = solutions
+            .par_iter()
+            .map(|solution| (*solution, solve_day(*solution, &parts, &args.options)))
+            .collect();
+
+        for (solution, day_reports) in solved {
+            start_year(reporter.as_mut(), solution)?;
+            reports.extend(report_day(
+                solution,
+                &parts,
+                day_reports,
+                reporter.as_mut(),
+                &args.options,
+            )?);
+        }
+    }
+
+    reporter.finish()?;
+    args.options.baseline.apply(&reports)?;
+
+    if has_wrong_answers(&reports) {
+        process::exit(1);
+    }
 
-    println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+    Ok(())
 }
@@ -0,0 +1,16 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+pub mod answers;
+pub mod aoc_client;
+pub mod baseline;
+pub mod bench;
+pub mod examples;
+pub mod input_cache;
+pub mod markdown;
+pub mod report;
+pub mod runner;
+pub mod selection;
+pub mod solution;
+pub mod submissions;
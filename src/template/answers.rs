@@ -0,0 +1,63 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+/// Reads the accepted answer of `part` from `src/answers/NN.txt`.
+/// The answer to part one is on the first line of that file, the answer to part two on the second.
+pub fn expected_answer(year: u16, day: u8, part: u8) -> Option<String> {
+    let answers = crate::try_read_year_file(year, "answers", day).ok()?;
+    parse_answer(&answers, part)
+}
+
+/// Stores an accepted answer in `src/answers/NN.txt`, keeping the answer to the other part.
+pub fn save_answer(year: u16, day: u8, part: u8, answer: &str) -> std::io::Result<()> {
+    let path = crate::year_data_path(year, "answers", day);
+    let answers = std::fs::read_to_string(&path).unwrap_or_default();
+    // the answers of a year other than `DEFAULT_YEAR` may have no directory yet.
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, set_answer(&answers, part, answer))
+}
+
+pub(crate) fn parse_answer(answers: &str, part: u8) -> Option<String> {
+    answers
+        .lines()
+        .nth(usize::from(part).checked_sub(1)?)
+        .map(str::trim)
+        .filter(|answer| !answer.is_empty())
+        .map(String::from)
+}
+
+fn set_answer(answers: &str, part: u8, answer: &str) -> String {
+    let mut lines: Vec<&str> = answers.lines().collect();
+    let index = usize::from(part.max(1)) - 1;
+    if lines.len() <= index {
+        lines.resize(index + 1, "");
+    }
+    lines[index] = answer;
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answer() {
+        assert_eq!(parse_answer("24000\n45000\n", 1), Some("24000".into()));
+        assert_eq!(parse_answer("24000\n45000\n", 2), Some("45000".into()));
+        assert_eq!(parse_answer("2=-1=0\n", 2), None);
+        assert_eq!(parse_answer("\nCMZ\n", 1), None);
+        assert_eq!(parse_answer("24000", 0), None);
+    }
+
+    #[test]
+    fn test_set_answer() {
+        assert_eq!(set_answer("", 1, "24000"), "24000\n");
+        assert_eq!(set_answer("", 2, "45000"), "\n45000\n");
+        assert_eq!(set_answer("24000\n", 2, "45000"), "24000\n45000\n");
+        assert_eq!(set_answer("1\n45000\n", 1, "24000"), "24000\n45000\n");
+    }
+}
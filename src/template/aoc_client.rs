@@ -0,0 +1,375 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::{
+    input_cache::{InputCache, InputError, InputStatus},
+    markdown,
+    submissions::SubmitResponse,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Holds the value of the `session` cookie of a logged-in browser.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Overrides `DEFAULT_BASE_URL`, e.g. to point the client at a mock server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Overrides `advent_of_code::DEFAULT_YEAR` when no `--year` is given.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+/// Read from the home directory if `AOC_SESSION` is not set, same as `aoc-cli` does.
+const SESSION_FILE: &str = ".adventofcode.session";
+
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    BadStatus { url: String, status: u16 },
+    Transport(String),
+    Input(InputError),
+    IoError(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set {SESSION_ENV} or write it to \"~/{SESSION_FILE}\"."
+            ),
+            AocClientError::BadStatus { url, status } => {
+                write!(f, "request to {url} failed with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "could not reach adventofcode.com: {e}"),
+            AocClientError::Input(e) => write!(f, "{e}"),
+            AocClientError::IoError(e) => {
+                write!(f, "could not write output files to file system: {e}")
+            }
+        }
+    }
+}
+
+impl From<InputError> for AocClientError {
+    fn from(e: InputError) -> Self {
+        AocClientError::Input(e)
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+/// Fetches inputs and puzzle descriptions from Advent of Code for one year.
+#[derive(Clone)]
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+/// Leaves out the session cookie, which grants access to the account.
+impl fmt::Debug for AocClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AocClient")
+            .field("base_url", &self.base_url)
+            .field("session", &"<redacted>")
+            .field("year", &self.year)
+            .finish()
+    }
+}
+
+impl AocClient {
+    pub fn new(session: impl Into<String>, year: u16) -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.into(),
+            session: session.into(),
+            year,
+        }
+    }
+
+    pub fn with_base_url(self, base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').into(),
+            ..self
+        }
+    }
+
+    /// Configures the client from `AOC_SESSION` (or `~/.adventofcode.session`), `AOC_BASE_URL`
+    /// and `AOC_YEAR`. `year` takes precedence over `AOC_YEAR`.
+    pub fn from_env(year: Option<u16>) -> Result<Self, AocClientError> {
+        let session = env::var(SESSION_ENV)
+            .ok()
+            .or_else(|| fs::read_to_string(home_dir()?.join(SESSION_FILE)).ok())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+            .ok_or(AocClientError::MissingSession)?;
+
+        let client = Self::new(session, resolve_year(year));
+        Ok(match env::var(BASE_URL_ENV) {
+            Ok(base_url) => client.with_base_url(base_url),
+            Err(_) => client,
+        })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, AocClientError> {
+        self.get(&format!("/{}/day/{day}/input", self.year))
+    }
+
+    /// The puzzle description of `day` as markdown, including part two once it is unlocked.
+    pub fn puzzle(&self, day: u8) -> Result<String, AocClientError> {
+        let html = self.get(&format!("/{}/day/{day}", self.year))?;
+        Ok(markdown::from_html(&html))
+    }
+
+    /// Submits `answer` for `part` of `day`. Check it against the `SubmissionHistory` first.
+    pub fn submit(
+        &self,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitResponse, AocClientError> {
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, self.year);
+        let level = part.to_string();
+        let response = self
+            .request("POST", &url)
+            .send_form(&[("level", &level), ("answer", answer)]);
+
+        let html = Self::read_response(&url, response)?;
+        Ok(SubmitResponse::parse(&markdown::from_html(&html)))
+    }
+
+    /// Stores the input of `day` in `cache` and writes its puzzle description to `puzzles` in the
+    /// directory of the year, see `advent_of_code::year_dir`.
+    /// Inputs that are already cached are not fetched again unless `force` is set, which also
+    /// overwrites inputs that were edited by hand. Returns whether the input was fetched.
+    pub fn download(
+        &self,
+        day: u8,
+        cache: &mut InputCache,
+        force: bool,
+    ) -> Result<bool, AocClientError> {
+        let status = cache.status(day)?;
+        if !force && matches!(status, InputStatus::Modified | InputStatus::Untracked) {
+            return Err(InputError::HandEdited {
+                day,
+                path: cache.path(day),
+            }
+            .into());
+        }
+        let fetch_input = force || status != InputStatus::Fresh;
+
+        // both are fetched before anything is written, so that a failed request changes nothing.
+        let input = fetch_input.then(|| self.input(day)).transpose()?;
+        let puzzle = self.puzzle(day)?;
+
+        if let Some(input) = input {
+            cache.store(day, &input, force)?;
+            cache.save()?;
+        }
+        write_file(&get_puzzle_path(self.year, day), &puzzle)?;
+        Ok(fetch_input)
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self.request("GET", &url).call();
+        Self::read_response(&url, response)
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        if cfg!(debug_assertions) {
+            println!("{method} {url}");
+        }
+
+        ureq::request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+    }
+
+    fn read_response(
+        url: &str,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, AocClientError> {
+        let response = response.map_err(|e| match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus {
+                url: url.to_string(),
+                status,
+            },
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        })?;
+
+        Ok(response.into_string()?)
+    }
+}
+
+/// `year` if given, otherwise `AOC_YEAR` or `advent_of_code::DEFAULT_YEAR`.
+pub fn resolve_year(year: Option<u16>) -> u16 {
+    year.or_else(|| env::var(YEAR_ENV).ok()?.parse().ok())
+        .unwrap_or(crate::DEFAULT_YEAR)
+}
+
+pub fn get_puzzle_path(year: u16, day: u8) -> PathBuf {
+    crate::year_dir(year)
+        .join("puzzles")
+        .join(format!("{day:02}.md"))
+}
+
+fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::template::submissions::Verdict;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves one canned `(status, body)` response per connection and returns the requests
+    /// it received, so tests can check what the client sent.
+    pub(crate) fn mock_server(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    let mut head = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(length) = line.to_lowercase().strip_prefix("content-length:")
+                        {
+                            content_length = length.trim().parse().unwrap();
+                        }
+                        if line.trim().is_empty() {
+                            break;
+                        }
+                        head.push_str(&line);
+                    }
+                    let mut request_body = vec![0; content_length];
+                    io::Read::read_exact(&mut reader, &mut request_body).unwrap();
+                    head.push_str(&String::from_utf8(request_body).unwrap());
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                    head
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, server) = mock_server(vec![(200, "1000\n2000\n".into())]);
+        let client = AocClient::new("abc123", 2022).with_base_url(base_url + "/");
+
+        assert_eq!(client.input(1).unwrap(), "1000\n2000\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("session=abc123"));
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let (base_url, server) = mock_server(vec![(
+            200,
+            "<main><article><h2>--- Day 7 ---</h2><p>Hello.</p></article></main>".into(),
+        )]);
+        let client = AocClient::new("abc123", 2021).with_base_url(base_url);
+
+        assert_eq!(client.puzzle(7).unwrap(), "## --- Day 7 ---\n\nHello.\n");
+        assert!(server.join().unwrap()[0].starts_with("GET /2021/day/7 HTTP/1.1"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = mock_server(vec![(
+            200,
+            "<main><article><p>That's not the right answer; your answer is too low. Please wait one minute before trying again.</p></article></main>".into(),
+        )]);
+        let client = AocClient::new("abc123", 2022).with_base_url(base_url);
+
+        let response = client.submit(1, 2, "45000").unwrap();
+        assert_eq!(response.verdict, Verdict::TooLow);
+        assert_eq!(response.wait, Some(std::time::Duration::from_secs(60)));
+
+        let request = &server.join().unwrap()[0];
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=45000"));
+    }
+
+    #[test]
+    fn test_bad_status() {
+        let (base_url, server) = mock_server(vec![(404, "Not Found".into())]);
+        let client = AocClient::new("abc123", 2022).with_base_url(base_url);
+
+        assert!(matches!(
+            client.input(26),
+            Err(AocClientError::BadStatus { status: 404, .. })
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_debug_hides_session() {
+        let client = AocClient::new("abc123", 2022);
+        assert!(!format!("{client:?}").contains("abc123"));
+    }
+
+    #[test]
+    fn test_download_keeps_edited_input() {
+        let dir = env::temp_dir().join("aoc_client_download_keeps_edited_input");
+        let _ = fs::remove_dir_all(&dir);
+        let mut cache = InputCache::open(&dir).unwrap();
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(cache.path(1), "1000\n").unwrap();
+
+        // nothing listens here, so any request would fail with a transport error.
+        let client = AocClient::new("abc123", 2022).with_base_url("http://127.0.0.1:9");
+        let result = client.download(1, &mut cache, false);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(
+            result,
+            Err(AocClientError::Input(InputError::HandEdited { day: 1, .. }))
+        ));
+    }
+}
@@ -0,0 +1,280 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde_json::{json, Value};
+
+use super::report::SolveReport;
+
+pub const DEFAULT_BASELINE_PATH: &str = "baseline.json";
+
+/// Recorded solve times per year, day and part, to detect slowdowns after a refactor.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    timings: BTreeMap<(u16, u8, u8), Duration>,
+}
+
+/// A part that got slower than the baseline allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    pub fn slowdown_percent(&self) -> f64 {
+        (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1_f64) * 100_f64
+    }
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let records: Vec<Value> = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+        let timings = records
+            .iter()
+            .map(|record| {
+                let field = |key: &str| {
+                    record[key].as_u64().ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("baseline record without \"{key}\": {record}"),
+                        )
+                    })
+                };
+                // baselines saved before multi-year support have no year.
+                let year = match record.get("year") {
+                    Some(_) => field("year")? as u16,
+                    None => crate::DEFAULT_YEAR,
+                };
+                Ok((
+                    (year, field("day")? as u8, field("part")? as u8),
+                    Duration::from_nanos(field("elapsed_ns")?),
+                ))
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self { timings })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let records: Vec<Value> = self
+            .timings
+            .iter()
+            .map(|((year, day, part), elapsed)| {
+                json!({ "year": year, "day": day, "part": part, "elapsed_ns": elapsed.as_nanos() as u64 })
+            })
+            .collect();
+
+        fs::write(path, serde_json::to_string_pretty(&records)? + "\n")
+    }
+
+    /// Records the times of all solved parts in `reports`, keeping other days as they are.
+    pub fn update(&mut self, reports: &[SolveReport]) {
+        for report in reports.iter().filter(|report| report.solved()) {
+            self.timings
+                .insert((report.year, report.day, report.part), report.elapsed);
+        }
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<Duration> {
+        self.timings.get(&(year, day, part)).copied()
+    }
+
+    /// Solved parts in `reports` that are more than `max_slowdown_percent` slower than recorded.
+    pub fn regressions(
+        &self,
+        reports: &[SolveReport],
+        max_slowdown_percent: u32,
+    ) -> Vec<Regression> {
+        reports
+            .iter()
+            .filter(|report| report.solved())
+            .filter_map(|report| {
+                let regression = Regression {
+                    year: report.year,
+                    day: report.day,
+                    part: report.part,
+                    baseline: self.get(report.year, report.day, report.part)?,
+                    current: report.elapsed,
+                };
+                (regression.slowdown_percent() > max_slowdown_percent as f64).then_some(regression)
+            })
+            .collect()
+    }
+}
+
+/// What to do with the baseline after a run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaselineOptions {
+    pub path: PathBuf,
+    pub save: bool,
+    pub compare: bool,
+    pub max_slowdown_percent: u32,
+}
+
+impl Default for BaselineOptions {
+    fn default() -> Self {
+        Self {
+            path: crate::root_dir().join(DEFAULT_BASELINE_PATH),
+            save: false,
+            compare: false,
+            max_slowdown_percent: 10,
+        }
+    }
+}
+
+impl BaselineOptions {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let default = Self::default();
+        Ok(Self {
+            path: args
+                .opt_value_from_str("--baseline")?
+                .unwrap_or(default.path),
+            save: args.contains("--save-baseline"),
+            compare: args.contains("--compare-baseline"),
+            max_slowdown_percent: args
+                .opt_value_from_str("--max-slowdown")?
+                .unwrap_or(default.max_slowdown_percent),
+        })
+    }
+
+    /// Compares `reports` against the baseline and then saves them, if requested.
+    pub fn apply(&self, reports: &[SolveReport]) -> io::Result<()> {
+        if self.compare {
+            let baseline = Baseline::load(&self.path).map_err(|e| {
+                io::Error::new(
+                    e.kind(),
+                    format!("could not read baseline \"{}\": {e}", self.path.display()),
+                )
+            })?;
+            let regressions = baseline.regressions(reports, self.max_slowdown_percent);
+
+            if regressions.is_empty() {
+                eprintln!(
+                    "🎄 No part is more than {}% slower than the baseline.",
+                    self.max_slowdown_percent
+                );
+            }
+
+            for regression in regressions {
+                eprintln!(
+                    "⚠️  Day {:02} of {} part {} is {:.1}% slower than the baseline ({:.2?} -> {:.2?}).",
+                    regression.day,
+                    regression.year,
+                    regression.part,
+                    regression.slowdown_percent(),
+                    regression.baseline,
+                    regression.current
+                );
+            }
+        }
+
+        if self.save {
+            let mut baseline = match Baseline::load(&self.path) {
+                Ok(baseline) => baseline,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Baseline::default(),
+                Err(e) => return Err(e),
+            };
+            baseline.update(reports);
+            baseline.save(&self.path)?;
+            eprintln!("🎄 Saved baseline to \"{}\".", self.path.display());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::report::Status;
+
+    fn report(day: u8, part: u8, elapsed_ms: u64, status: Status) -> SolveReport {
+        SolveReport {
+            year: 2022,
+            day,
+            part,
+            answer: String::new(),
+            elapsed: Duration::from_millis(elapsed_ms),
+            status,
+            bench: None,
+            expected: None,
+        }
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join("aoc_baseline_test_save_and_load.json");
+
+        let mut baseline = Baseline::default();
+        baseline.update(&[
+            report(17, 1, 3, Status::Solved),
+            report(17, 2, 5, Status::NotSolved),
+        ]);
+        baseline.save(&path).unwrap();
+        let loaded = Baseline::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, baseline);
+        assert_eq!(loaded.get(2022, 17, 1), Some(Duration::from_millis(3)));
+        assert_eq!(loaded.get(2022, 17, 2), None);
+        assert_eq!(loaded.get(2021, 17, 1), None);
+    }
+
+    #[test]
+    fn test_load_without_year() {
+        let path = std::env::temp_dir().join("aoc_baseline_test_load_without_year.json");
+
+        fs::write(&path, r#"[{ "day": 3, "part": 1, "elapsed_ns": 1000 }]"#).unwrap();
+        let loaded = Baseline::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            loaded.get(crate::DEFAULT_YEAR, 3, 1),
+            Some(Duration::from_micros(1))
+        );
+    }
+
+    #[test]
+    fn test_regressions() {
+        let mut baseline = Baseline::default();
+        baseline.update(&[
+            report(19, 1, 10, Status::Solved),
+            report(19, 2, 10, Status::Solved),
+            report(24, 1, 10, Status::Solved),
+        ]);
+
+        let regressions = baseline.regressions(
+            &[
+                report(19, 1, 30, Status::Solved),
+                report(19, 2, 11, Status::Solved),
+                report(24, 1, 30, Status::Panicked("oh no".into())),
+                report(24, 2, 30, Status::Solved),
+            ],
+            10,
+        );
+
+        assert_eq!(
+            regressions,
+            vec![Regression {
+                year: 2022,
+                day: 19,
+                part: 1,
+                baseline: Duration::from_millis(10),
+                current: Duration::from_millis(30),
+            }]
+        );
+        assert!((regressions[0].slowdown_percent() - 200_f64).abs() < 1.0e-6);
+    }
+}
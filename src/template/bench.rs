@@ -0,0 +1,94 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::{Duration, Instant};
+
+/// Timings of repeated runs of one part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        Some(Self {
+            runs: sorted.len(),
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+/// Calls `f` `runs` times after a warm-up of a tenth of that (at least once) and collects its timings.
+pub fn bench<T>(mut f: impl FnMut() -> T, runs: usize) -> Option<BenchStats> {
+    for _ in 0..(runs / 10).max(1) {
+        std::hint::black_box(f());
+    }
+
+    let samples: Vec<Duration> = (0..runs)
+        .map(|_| {
+            let timer = Instant::now();
+            std::hint::black_box(f());
+            timer.elapsed()
+        })
+        .collect();
+
+    BenchStats::from_samples(&samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_samples() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_millis);
+
+        assert_eq!(
+            BenchStats::from_samples(&samples),
+            Some(BenchStats {
+                runs: 8,
+                min: Duration::from_millis(2),
+                median: Duration::from_micros(4500),
+                mean: Duration::from_millis(5),
+                stddev: Duration::from_millis(2),
+            })
+        );
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench() {
+        let mut calls = 0;
+        let stats = bench(|| calls += 1, 20).unwrap();
+
+        assert_eq!(stats.runs, 20);
+        assert_eq!(calls, 22);
+        assert!(stats.min <= stats.median);
+    }
+}
@@ -0,0 +1,333 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+use super::answers::parse_answer;
+
+/// `src/examples/NN.txt` for the first example of a day, which is also example `Some(1)`,
+/// and `src/examples/NN-K.txt` for the further ones, starting at `NN-2.txt`.
+/// Examples of another year than `DEFAULT_YEAR` live in `src/{year}/examples`.
+pub fn example_path(year: u16, day: u8, example: Option<u8>) -> PathBuf {
+    let name = match example {
+        None | Some(1) => format!("{day:02}.txt"),
+        Some(example) => format!("{day:02}-{example}.txt"),
+    };
+    crate::year_dir(year).join("examples").join(name)
+}
+
+/// Reads example `example` of `day`, counting from 1, panicking with the reason if it can't be used.
+pub fn read_example(day: u8, example: u8) -> String {
+    read_year_example(crate::DEFAULT_YEAR, day, example)
+}
+
+/// Like `read_example`, for days of another year than `DEFAULT_YEAR`.
+pub fn read_year_example(year: u16, day: u8, example: u8) -> String {
+    let path = example_path(year, day, Some(example));
+    crate::try_read_path(day, path).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Reads the answer to `part` from the sidecar of an example, e.g. `src/examples/09-2.expected`.
+/// Like `src/answers/NN.txt`, it has the answer to part one on its first line and to part two on its second.
+pub fn expected_example_answer(
+    year: u16,
+    day: u8,
+    example: Option<u8>,
+    part: u8,
+) -> Option<String> {
+    let sidecar = example_path(year, day, example).with_extension("expected");
+    let answers = crate::try_read_path(day, sidecar).ok()?;
+    parse_answer(&answers, part)
+}
+
+/// Runs `solver` on an example and compares the result to the answer in its sidecar.
+/// Called by the tests generated by `example_tests!`.
+pub fn check_example<T: Display>(
+    year: u16,
+    day: u8,
+    example: Option<u8>,
+    part: u8,
+    solver: fn(&str) -> Option<T>,
+) {
+    let path = example_path(year, day, example);
+    let input = crate::try_read_path(day, &path).unwrap_or_else(|e| panic!("{e}"));
+    let expected = expected_example_answer(year, day, example, part);
+
+    assert_eq!(
+        solver(&input).map(|answer| answer.to_string()),
+        expected,
+        "wrong answer to part {part} of \"{}\"",
+        path.display()
+    );
+}
+
+/// An example found in a puzzle description, with the answers the description gives for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    /// The answers to part one and part two.
+    pub answers: [Option<String>; 2],
+}
+
+impl Example {
+    /// The contents of the `.expected` sidecar of this example.
+    pub fn sidecar(&self) -> String {
+        let [part_one, part_two] = &self.answers;
+        match (part_one, part_two) {
+            (part_one, Some(part_two)) => {
+                format!("{}\n{part_two}\n", part_one.as_deref().unwrap_or_default())
+            }
+            (Some(part_one), None) => format!("{part_one}\n"),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+/// Finds the examples in the markdown of a puzzle in `src/puzzles`.
+///
+/// The example of a part is the first code block introduced by a paragraph that mentions an
+/// example, its answer the last emphasized code in that part. Part two usually reuses the
+/// example of part one, unless it introduces one of its own.
+pub fn extract_examples(markdown: &str) -> Vec<Example> {
+    let (part_one, part_two) = match markdown.find("--- Part Two ---") {
+        Some(index) => (&markdown[..index], Some(&markdown[index..])),
+        None => (markdown, None),
+    };
+
+    let mut examples: Vec<Example> = vec![];
+
+    if let Some(input) = example_block(part_one, true) {
+        examples.push(Example {
+            input,
+            answers: [emphasized_answer(part_one), None],
+        });
+    }
+
+    if let Some(part_two) = part_two {
+        let answer = emphasized_answer(part_two);
+        match (example_block(part_two, false), examples.first_mut()) {
+            (Some(input), _) => examples.push(Example {
+                input,
+                answers: [None, answer],
+            }),
+            (None, Some(example)) => example.answers[1] = answer,
+            (None, None) => {}
+        }
+    }
+
+    examples
+}
+
+/// The contents of the first code block that follows a paragraph mentioning an example.
+/// Falls back to the first code block at all if `fallback` is set.
+fn example_block(markdown: &str, fallback: bool) -> Option<String> {
+    let mut blocks = vec![];
+    let mut preceding = "";
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            match block.take() {
+                Some(contents) => blocks.push((preceding, contents)),
+                None => block = Some(String::new()),
+            }
+        } else if let Some(contents) = block.as_mut() {
+            contents.push_str(line);
+            contents.push('\n');
+        } else if !line.trim().is_empty() {
+            preceding = line;
+        }
+    }
+
+    let introduced = blocks
+        .iter()
+        .position(|(preceding, _)| preceding.to_lowercase().contains("example"));
+    let index = introduced.or((fallback && !blocks.is_empty()).then_some(0))?;
+
+    Some(blocks.swap_remove(index).1.trim_end().to_string() + "\n")
+}
+
+/// The last emphasized code, which is how puzzle descriptions highlight the answer to an example.
+fn emphasized_answer(markdown: &str) -> Option<String> {
+    let emphasized = Regex::new(r"`\*([^`*]+)\*`|\*`([^`*]+)`\*").unwrap();
+    emphasized
+        .captures_iter(markdown)
+        .last()
+        .and_then(|captures| captures.get(1).or_else(|| captures.get(2)))
+        .map(|answer| answer.as_str().trim().to_string())
+}
+
+/// Writes `examples` to `src/examples` as `NN.txt`, `NN-2.txt` … together with their sidecars.
+/// Unless `force` is set, refuses to overwrite examples that are not empty.
+pub fn write_examples(
+    year: u16,
+    day: u8,
+    examples: &[Example],
+    force: bool,
+) -> io::Result<Vec<PathBuf>> {
+    let paths: Vec<PathBuf> = (0..examples.len())
+        .map(|index| match index {
+            0 => example_path(year, day, None),
+            index => example_path(year, day, Some(index as u8 + 1)),
+        })
+        .collect();
+
+    if !force {
+        if let Some(path) = paths.iter().find(|path| has_contents(path)) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "\"{}\" is not empty. Pass `--force` to overwrite it.",
+                    path.display()
+                ),
+            ));
+        }
+    }
+
+    for (example, path) in examples.iter().zip(&paths) {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, &example.input)?;
+        fs::write(path.with_extension("expected"), example.sidecar())?;
+    }
+
+    Ok(paths)
+}
+
+fn has_contents(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Generates a `#[test]` for every example of a day in `src/examples` and every part that its
+/// `.expected` sidecar has an answer for. Expects `part_one` and `part_two` to be in scope.
+/// Pass the day the same way as to `solution!`, e.g. `example_tests!(9)` or
+/// `example_tests!(9, year = 2021)` for a day of another year.
+#[macro_export]
+macro_rules! example_tests {
+    ($day:literal) => {
+        #[cfg(test)]
+        mod example_tests {
+            use super::*;
+
+            include!(concat!(env!("OUT_DIR"), "/example_tests_", $day, ".rs"));
+        }
+    };
+    ($day:literal, year = $year:literal) => {
+        #[cfg(test)]
+        mod example_tests {
+            use super::*;
+
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/example_tests_",
+                $year,
+                "_",
+                $day,
+                ".rs"
+            ));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_path() {
+        assert!(example_path(2022, 9, None).ends_with("src/examples/09.txt"));
+        assert!(example_path(2022, 9, Some(1)).ends_with("src/examples/09.txt"));
+        assert!(example_path(2022, 9, Some(2)).ends_with("src/examples/09-2.txt"));
+        assert!(example_path(2021, 9, None).ends_with("src/2021/examples/09.txt"));
+    }
+
+    #[test]
+    fn test_expected_example_answer() {
+        assert_eq!(expected_example_answer(2022, 9, Some(2), 1), None);
+        assert_eq!(
+            expected_example_answer(2022, 9, Some(2), 2),
+            Some("36".into())
+        );
+        assert_eq!(expected_example_answer(2022, 9, Some(3), 2), None);
+        assert_eq!(expected_example_answer(2021, 9, Some(2), 2), None);
+    }
+
+    const PUZZLE: &str = "## --- Day 9: Rope Bridge ---
+
+Consider a rope with a knot at each end.
+
+```
+....
+.TH.
+....
+```
+
+For example:
+
+```
+R 4
+U 4
+
+```
+
+So, there are `*13*` positions the tail visited at least once.
+
+## --- Part Two ---
+
+Rather than two knots, you now must simulate a rope consisting of `*ten*` knots.
+
+```
+......
+...H..
+```
+
+Now, consider a larger example:
+
+```
+R 5
+U 8
+```
+
+Now, the tail (`9`) visits `*36*` positions.
+";
+
+    #[test]
+    fn test_extract_examples() {
+        assert_eq!(
+            extract_examples(PUZZLE),
+            vec![
+                Example {
+                    input: "R 4\nU 4\n".into(),
+                    answers: [Some("13".into()), None],
+                },
+                Example {
+                    input: "R 5\nU 8\n".into(),
+                    answers: [None, Some("36".into())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_shared_example() {
+        let puzzle = "For example:\n\n```\n1000\n```\n\nThat is *`24000`*.\n\n\\--- Part Two ---\n----------\n\nThat is `*45000*` calories.\n";
+
+        let examples = extract_examples(puzzle);
+        assert_eq!(
+            examples,
+            vec![Example {
+                input: "1000\n".into(),
+                answers: [Some("24000".into()), Some("45000".into())],
+            }]
+        );
+        assert_eq!(examples[0].sidecar(), "24000\n45000\n");
+    }
+}
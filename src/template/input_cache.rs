@@ -0,0 +1,472 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::{json, Value};
+
+use crate::ReadError;
+
+/// Lives next to the inputs and records what was downloaded.
+pub const MANIFEST_FILE: &str = ".cache.json";
+
+/// What was recorded about an input when it was downloaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    pub checksum: String,
+    pub len: usize,
+    /// Unix timestamp in seconds.
+    pub fetched_at: u64,
+}
+
+/// The state of an input file compared to what was downloaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputStatus {
+    Missing,
+    /// An empty file, as created by `cargo scaffold`.
+    Empty,
+    /// Shorter than when it was downloaded and cut off mid-line, like an interrupted download.
+    Truncated,
+    /// Changed since it was downloaded.
+    Modified,
+    /// Present, but not downloaded by the cache.
+    Untracked,
+    Fresh,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// A missing or empty file in `src/inputs`, which `cargo download` can fix.
+    NotDownloaded {
+        year: u16,
+        source: ReadError,
+    },
+    Read(ReadError),
+    /// Shorter than when it was downloaded and cut off mid-line.
+    Truncated {
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+    /// The input would overwrite a file that was changed or placed there by hand.
+    HandEdited {
+        day: u8,
+        path: PathBuf,
+    },
+    IoError(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotDownloaded { year, source } => write!(
+                f,
+                "{source} Run `{}` to fetch it.",
+                download_command(*year, source.day())
+            ),
+            InputError::Read(e) => write!(f, "{e}"),
+            InputError::Truncated { year, day, path } => write!(
+                f,
+                "input of day {day:02} at \"{}\" is shorter than when it was downloaded and ends mid-line. Unless you cut it yourself, run `{} --force` to fetch it again.",
+                path.display(),
+                download_command(*year, *day)
+            ),
+            InputError::HandEdited { day, path } => write!(
+                f,
+                "input of day {day:02} at \"{}\" was not downloaded or was edited since. Pass `--force` to overwrite it.",
+                path.display()
+            ),
+            InputError::IoError(e) => write!(f, "could not access input: {e}"),
+        }
+    }
+}
+
+fn download_command(year: u16, day: u8) -> String {
+    format!("cargo download {day}{}", crate::year_flag(year))
+}
+
+impl From<ReadError> for InputError {
+    fn from(e: ReadError) -> Self {
+        InputError::Read(e)
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(e: io::Error) -> Self {
+        InputError::IoError(e)
+    }
+}
+
+/// The inputs in a directory together with what is known about their downloads.
+/// Reading never touches the network, so solving works offline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputCache {
+    dir: PathBuf,
+    /// The year the inputs belong to, for hints on how to download them.
+    year: u16,
+    entries: BTreeMap<u8, CacheEntry>,
+}
+
+impl InputCache {
+    pub fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        let manifest = match fs::read_to_string(dir.join(MANIFEST_FILE)) {
+            Ok(manifest) => manifest,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => "[]".into(),
+            Err(e) => return Err(e),
+        };
+        let records: Vec<Value> = serde_json::from_str(&manifest)?;
+
+        let entries = records
+            .iter()
+            .map(|record| {
+                let invalid = |key: &str| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("input cache record without \"{key}\": {record}"),
+                    )
+                };
+                let number = |key: &str| record[key].as_u64().ok_or_else(|| invalid(key));
+                Ok((
+                    number("day")? as u8,
+                    CacheEntry {
+                        checksum: record["checksum"]
+                            .as_str()
+                            .ok_or_else(|| invalid("checksum"))?
+                            .to_string(),
+                        len: number("len")? as usize,
+                        fetched_at: number("fetched_at")?,
+                    },
+                ))
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self {
+            dir,
+            year: crate::DEFAULT_YEAR,
+            entries,
+        })
+    }
+
+    /// The cache of `src/inputs`, or of `src/{year}/inputs` for another year than `DEFAULT_YEAR`.
+    pub fn open_year(year: u16) -> io::Result<Self> {
+        let cache = Self::open(crate::year_dir(year).join("inputs"))?;
+        Ok(Self { year, ..cache })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let records: Vec<Value> = self
+            .entries
+            .iter()
+            .map(|(day, entry)| {
+                json!({
+                    "day": day,
+                    "checksum": entry.checksum,
+                    "len": entry.len,
+                    "fetched_at": entry.fetched_at,
+                })
+            })
+            .collect();
+
+        fs::create_dir_all(&self.dir)?;
+        fs::write(
+            self.dir.join(MANIFEST_FILE),
+            serde_json::to_string_pretty(&records)? + "\n",
+        )
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("{day:02}.txt"))
+    }
+
+    pub fn entry(&self, day: u8) -> Option<&CacheEntry> {
+        self.entries.get(&day)
+    }
+
+    pub fn status(&self, day: u8) -> io::Result<InputStatus> {
+        let input = match fs::read_to_string(self.path(day)) {
+            Ok(input) => input,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(InputStatus::Missing),
+            Err(e) => return Err(e),
+        };
+        Ok(self.status_of(day, &input))
+    }
+
+    fn status_of(&self, day: u8, input: &str) -> InputStatus {
+        match self.entry(day) {
+            _ if input.is_empty() => InputStatus::Empty,
+            None => InputStatus::Untracked,
+            // Downloads end with a newline, inputs trimmed by hand to whole lines are edits.
+            Some(entry) if input.len() < entry.len && !input.ends_with('\n') => {
+                InputStatus::Truncated
+            }
+            Some(entry) if checksum(input) != entry.checksum => InputStatus::Modified,
+            Some(_) => InputStatus::Fresh,
+        }
+    }
+
+    /// Reads the input of `day`. Inputs edited by hand are fine, broken ones are not.
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        let path = self.path(day);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Err(InputError::NotDownloaded {
+                    year: self.year,
+                    source: ReadError::Missing { day, path },
+                })
+            }
+            Err(source) => return Err(ReadError::Unreadable { day, path, source }.into()),
+        };
+
+        if let Some(actual) = self.day_of(&input).filter(|actual| *actual != day) {
+            return Err(ReadError::WrongDay { day, path, actual }.into());
+        }
+
+        match self.status_of(day, &input) {
+            InputStatus::Empty => Err(InputError::NotDownloaded {
+                year: self.year,
+                source: ReadError::Empty { day, path },
+            }),
+            InputStatus::Truncated => Err(InputError::Truncated {
+                year: self.year,
+                day,
+                path,
+            }),
+            _ => Ok(input),
+        }
+    }
+
+    /// The day that `input` was downloaded for, if it is cached.
+    fn day_of(&self, input: &str) -> Option<u8> {
+        let checksum = checksum(input);
+        self.entries
+            .iter()
+            .find(|(_, entry)| entry.checksum == checksum)
+            .map(|(day, _)| *day)
+    }
+
+    /// Writes a downloaded input and records it. Call `save` afterwards to persist the record.
+    /// Unless `force` is set, refuses to overwrite an input that was changed or placed by hand.
+    pub fn store(&mut self, day: u8, input: &str, force: bool) -> Result<(), InputError> {
+        let path = self.path(day);
+
+        if input.is_empty() {
+            return Err(ReadError::Empty { day, path }.into());
+        }
+
+        let status = self.status(day)?;
+        if !force && matches!(status, InputStatus::Modified | InputStatus::Untracked) {
+            return Err(InputError::HandEdited { day, path });
+        }
+
+        fs::create_dir_all(&self.dir)?;
+        fs::write(&path, input)?;
+
+        self.entries.insert(
+            day,
+            CacheEntry {
+                checksum: checksum(input),
+                len: input.len(),
+                fetched_at: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |elapsed| elapsed.as_secs()),
+            },
+        );
+        Ok(())
+    }
+}
+
+/// Reads the input of `day` of `year` from its cache without going online.
+/// Unlike `try_read_file("inputs", day)`, this also detects truncated inputs and inputs of another day.
+pub fn read_input(year: u16, day: u8) -> Result<String, InputError> {
+    InputCache::open_year(year)?.read(day)
+}
+
+/// Where a day binary reads its input from, chosen with `--input <path>` or `--input -`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// `src/inputs`, through `read_input`.
+    #[default]
+    Cache,
+    File(PathBuf),
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(path.into()),
+        })
+    }
+}
+
+impl InputSource {
+    pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::Cache => read_input(year, day),
+            InputSource::File(path) => Ok(crate::try_read_path(day, path)?),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                if input.is_empty() {
+                    let path = "<stdin>".into();
+                    return Err(ReadError::Empty { day, path }.into());
+                }
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// 64-bit FNV-1a, which is stable across Rust versions unlike `DefaultHasher`.
+fn checksum(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    format!("fnv1a64:{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temp_cache(name: &str) -> InputCache {
+        let dir = env::temp_dir().join(format!("aoc_input_cache_{name}"));
+        let _ = fs::remove_dir_all(&dir);
+        InputCache::open(dir).unwrap()
+    }
+
+    #[test]
+    fn test_download_hint() {
+        let cache = temp_cache("download_hint");
+        assert!(cache
+            .read(4)
+            .unwrap_err()
+            .to_string()
+            .ends_with("Run `cargo download 4` to fetch it."));
+
+        let cache = InputCache {
+            year: 2021,
+            ..cache
+        };
+        assert!(cache
+            .read(4)
+            .unwrap_err()
+            .to_string()
+            .ends_with("Run `cargo download 4 --year 2021` to fetch it."));
+    }
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(""), "fnv1a64:cbf29ce484222325");
+        assert_eq!(checksum("a"), "fnv1a64:af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_store_and_read() {
+        let mut cache = temp_cache("store_and_read");
+
+        assert!(matches!(
+            cache.read(1),
+            Err(InputError::NotDownloaded {
+                source: ReadError::Missing { day: 1, .. },
+                ..
+            })
+        ));
+        assert_eq!(cache.status(1).unwrap(), InputStatus::Missing);
+
+        cache.store(1, "1000\n2000\n", false).unwrap();
+        cache.save().unwrap();
+
+        let reopened = InputCache::open(&cache.dir).unwrap();
+        assert_eq!(reopened, cache);
+        assert_eq!(reopened.status(1).unwrap(), InputStatus::Fresh);
+        assert_eq!(reopened.read(1).unwrap(), "1000\n2000\n");
+
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+
+    #[test]
+    fn test_broken_inputs() {
+        let mut cache = temp_cache("broken_inputs");
+        cache.store(2, "A Y\nB X\nC Z\n", false).unwrap();
+
+        fs::write(cache.path(2), "A Y\nB").unwrap();
+        assert_eq!(cache.status(2).unwrap(), InputStatus::Truncated);
+        assert!(matches!(cache.read(2), Err(InputError::Truncated { .. })));
+
+        fs::write(cache.path(2), "A Y\n").unwrap();
+        assert_eq!(cache.status(2).unwrap(), InputStatus::Modified);
+        assert_eq!(cache.read(2).unwrap(), "A Y\n");
+
+        fs::write(cache.path(2), "").unwrap();
+        assert_eq!(cache.status(2).unwrap(), InputStatus::Empty);
+        assert!(matches!(
+            cache.read(2),
+            Err(InputError::NotDownloaded {
+                source: ReadError::Empty { .. },
+                ..
+            })
+        ));
+        cache.store(2, "A Y\nB X\nC Z\n", false).unwrap();
+
+        assert!(matches!(
+            cache.store(2, "", true),
+            Err(InputError::Read(ReadError::Empty { .. }))
+        ));
+
+        cache.store(1, "1000\n2000\n", false).unwrap();
+        fs::write(cache.path(2), "1000\n2000\n").unwrap();
+        assert!(matches!(
+            cache.read(2),
+            Err(InputError::Read(ReadError::WrongDay {
+                day: 2,
+                actual: 1,
+                ..
+            }))
+        ));
+
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+
+    #[test]
+    fn test_refuses_to_clobber_edits() {
+        let mut cache = temp_cache("refuses_to_clobber_edits");
+        cache.store(3, "vJrwpWtwJgWrhcsFMMfFFhFp\n", false).unwrap();
+
+        fs::write(cache.path(3), "vJrwpWtwJgWrhcsFMMfFFhFP\n").unwrap();
+        assert_eq!(cache.status(3).unwrap(), InputStatus::Modified);
+        assert_eq!(cache.read(3).unwrap(), "vJrwpWtwJgWrhcsFMMfFFhFP\n");
+        assert!(matches!(
+            cache.store(3, "vJrwpWtwJgWrhcsFMMfFFhFp\n", false),
+            Err(InputError::HandEdited { .. })
+        ));
+
+        fs::write(cache.path(4), "2-4,6-8\n").unwrap();
+        assert_eq!(cache.status(4).unwrap(), InputStatus::Untracked);
+        assert!(matches!(
+            cache.store(4, "2-4,6-8\n", false),
+            Err(InputError::HandEdited { .. })
+        ));
+
+        cache.store(3, "vJrwpWtwJgWrhcsFMMfFFhFp\n", true).unwrap();
+        assert_eq!(cache.status(3).unwrap(), InputStatus::Fresh);
+
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+}
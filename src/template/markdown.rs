@@ -0,0 +1,279 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Converts the puzzle description of an Advent of Code page to markdown.
+/// Only the `<article>` elements are kept, everything around them is site chrome.
+pub fn from_html(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let body = match rest[start..].find('>') {
+            Some(end) => &rest[start + end + 1..],
+            None => break,
+        };
+        let end = body.find("</article>").unwrap_or(body.len());
+        markdown.push_str(&convert(&body[..end]));
+        markdown.push_str("\n\n");
+        rest = &body[end..];
+    }
+
+    collapse_blank_lines(&markdown)
+}
+
+fn convert(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(tag_start) = rest.strip_prefix('<') {
+            let end = tag_start.find('>').unwrap_or(tag_start.len());
+            let tag = &tag_start[..end];
+            rest = tag_start.get(end + 1..).unwrap_or_default();
+
+            let (closing, tag) = match tag.strip_prefix('/') {
+                Some(tag) => (true, tag),
+                None => (false, tag),
+            };
+            let name = tag.split_whitespace().next().unwrap_or_default();
+
+            match (name, closing) {
+                ("h2", false) => out.push_str("## "),
+                ("h2" | "p" | "ul", true) => out.push_str("\n\n"),
+                ("pre", false) => {
+                    in_pre = true;
+                    out.push_str("```\n");
+                }
+                ("pre", true) => {
+                    in_pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                ("code", _) if !in_pre => out.push('`'),
+                ("em", _) if !in_pre => out.push('*'),
+                ("a", false) => {
+                    links.push(attribute(tag, "href").unwrap_or_default());
+                    out.push('[');
+                }
+                ("a", true) => {
+                    let href = links.pop().unwrap_or_default();
+                    out.push_str(&format!("]({href})"));
+                }
+                ("li", false) => out.push_str("- "),
+                ("li", true) => out.push('\n'),
+                _ => {}
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = decode_entities(&rest[..end]);
+            rest = &rest[end..];
+
+            if in_pre {
+                out.push_str(&text);
+            } else {
+                let text = text.replace('\n', " ");
+                if out.is_empty() || out.ends_with('\n') {
+                    out.push_str(text.trim_start());
+                } else {
+                    out.push_str(&text);
+                }
+            }
+        }
+    }
+
+    out
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let character = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => name
+                .strip_prefix('#')
+                .and_then(|code| code.parse().ok())
+                .and_then(char::from_u32),
+        });
+
+        match (character, entity) {
+            (Some(character), Some((_, end))) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Styles the markdown of `from_html` for the terminal: headings, code and code blocks in bold,
+/// emphasis in italic. Code blocks lose their fences and are indented instead.
+pub fn to_terminal(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_block = !in_block;
+            continue;
+        }
+
+        if in_block {
+            out.push_str(&format!("    {ANSI_BOLD}{line}{ANSI_RESET}\n"));
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            out.push_str(&format!("{ANSI_BOLD}{heading}{ANSI_RESET}\n"));
+        } else {
+            out.push_str(&style_inline(line));
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+/// Replaces the backticks of code and the asterisks of emphasis in `line` with styles.
+/// An asterisk without a closing one on the same line is kept as it is, e.g. in `2 * 3`.
+fn style_inline(line: &str) -> String {
+    let mut out = String::new();
+    let (mut code, mut emphasis) = (false, false);
+
+    for (index, character) in line.char_indices() {
+        match character {
+            '`' => code = !code,
+            '*' if emphasis || line[index + 1..].contains('*') => emphasis = !emphasis,
+            character => {
+                out.push(character);
+                continue;
+            }
+        }
+
+        // styles can't be turned off one by one, so reset and apply those still active.
+        out.push_str(ANSI_RESET);
+        if code {
+            out.push_str(ANSI_BOLD);
+        }
+        if emphasis {
+            out.push_str(ANSI_ITALIC);
+        }
+    }
+
+    if code || emphasis {
+        out.push_str(ANSI_RESET);
+    }
+    out
+}
+
+fn collapse_blank_lines(markdown: &str) -> String {
+    let mut collapsed = String::new();
+    let mut blank_lines = 0;
+
+    for line in markdown.trim().lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        collapsed.push_str(line);
+        collapsed.push('\n');
+    }
+
+    collapsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_html() {
+        let html = r#"<html><body><header>Advent of Code</header>
+<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The jungle must be <em>too</em> overgrown &amp; difficult to <a href="https://example.com/?a=1&amp;b=2" target="_blank">navigate</a>.</p>
+<p>For example:</p>
+<pre><code>1000
+<em>2000</em>
+&lt;3000&gt;
+</code></pre>
+<ul>
+<li>The first Elf is carrying <code><em>6000</em></code> Calories.</li>
+<li>The second Elf isn&#39;t.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>24000</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the top three.</p>
+</article>
+</main></body></html>"#;
+
+        assert_eq!(
+            from_html(html),
+            "## --- Day 1: Calorie Counting ---
+
+The jungle must be *too* overgrown & difficult to [navigate](https://example.com/?a=1&b=2).
+
+For example:
+
+```
+1000
+2000
+<3000>
+```
+
+- The first Elf is carrying `*6000*` Calories.
+- The second Elf isn't.
+
+## --- Part Two ---
+
+Find the top three.
+"
+        );
+    }
+
+    #[test]
+    fn test_to_terminal() {
+        let markdown = "## --- Day 1 ---\n\nIt is *too* `*6000*` or 2 * 3.\n\n```\n1000\n```\n";
+
+        assert_eq!(
+            to_terminal(markdown),
+            format!(
+                "{ANSI_BOLD}--- Day 1 ---{ANSI_RESET}\n\nIt is {ANSI_RESET}{ANSI_ITALIC}too{ANSI_RESET} {ANSI_RESET}{ANSI_BOLD}{ANSI_RESET}{ANSI_BOLD}{ANSI_ITALIC}6000{ANSI_RESET}{ANSI_BOLD}{ANSI_RESET} or 2 * 3.\n\n    {ANSI_BOLD}1000{ANSI_RESET}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_from_html_without_article() {
+        assert_eq!(from_html("<html><p>Please log in.</p></html>"), "");
+    }
+}
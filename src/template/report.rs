@@ -0,0 +1,777 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::{self, Display},
+    io::{self, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use serde_json::json;
+
+use super::bench::BenchStats;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// The outcome of running one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveReport {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// The median of `bench` if the part was benchmarked.
+    pub elapsed: Duration,
+    pub status: Status,
+    pub bench: Option<BenchStats>,
+    /// The accepted answer, if one was recorded.
+    pub expected: Option<String>,
+}
+
+impl SolveReport {
+    pub fn solved(&self) -> bool {
+        self.status == Status::Solved
+    }
+
+    /// Whether the answer matches the accepted one, if there is one.
+    pub fn correct(&self) -> Option<bool> {
+        self.expected
+            .as_ref()
+            .map(|expected| self.solved() && &self.answer == expected)
+    }
+
+    fn verdict(&self) -> Option<String> {
+        match (self.correct(), &self.expected) {
+            (Some(true), _) => Some("✔".into()),
+            (Some(false), Some(expected)) => Some(format!("✘ (expected: {expected})")),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    NotSolved,
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Status {
+    /// What went wrong beyond the status itself, e.g. the message of a panic.
+    pub fn message(&self) -> Option<String> {
+        match self {
+            Status::Solved | Status::NotSolved => None,
+            Status::Panicked(message) => Some(message.clone()),
+            Status::TimedOut(timeout) => Some(format!("after {timeout:.2?}")),
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::NotSolved => write!(f, "not solved"),
+            Status::Panicked(_) => write!(f, "panicked"),
+            Status::TimedOut(_) => write!(f, "timed out"),
+        }
+    }
+}
+
+/// Decides how the results of a run are presented.
+/// For every day, `day_started` is called before `part_started` and `part_solved` of each part.
+/// `finish` is called once after the last day.
+pub trait Reporter {
+    /// Called before the first day of every year, but only if a run spans several years.
+    fn year_started(&mut self, _year: u16) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn day_started(&mut self, _year: u16, _day: u8) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Called instead of solving the selected `parts` of a day that has no input.
+    fn day_skipped(&mut self, _year: u16, _day: u8, _parts: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn part_started(&mut self, _day: u8, _part: u8) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn part_solved(&mut self, report: &SolveReport) -> io::Result<()>;
+
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The colored 🎄 output of `cargo solve` and `cargo all`.
+pub struct TextReporter<W: Write> {
+    out: W,
+    total: Duration,
+    /// The year that is being run and its total so far, if the run spans several years.
+    year: Option<(u16, Duration)>,
+}
+
+impl<W: Write> TextReporter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            total: Duration::ZERO,
+            year: None,
+        }
+    }
+
+    fn finish_year(&mut self) -> io::Result<()> {
+        match self.year.take() {
+            Some((year, total)) => writeln!(
+                self.out,
+                "{ANSI_BOLD}Total {year}:{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET}",
+                total.as_secs_f64() * 1000_f64
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<W: Write> Reporter for TextReporter<W> {
+    fn year_started(&mut self, year: u16) -> io::Result<()> {
+        self.finish_year()?;
+        self.year = Some((year, Duration::ZERO));
+        writeln!(
+            self.out,
+            "{ANSI_BOLD}========== {year} =========={ANSI_RESET}"
+        )
+    }
+
+    fn day_started(&mut self, _year: u16, day: u8) -> io::Result<()> {
+        writeln!(self.out, "----------")?;
+        writeln!(self.out, "{ANSI_BOLD}| Day {day:02} |{ANSI_RESET}")?;
+        writeln!(self.out, "----------")
+    }
+
+    fn day_skipped(&mut self, _year: u16, _day: u8, _parts: &[u8]) -> io::Result<()> {
+        writeln!(self.out, "Not solved.")
+    }
+
+    fn part_started(&mut self, _day: u8, part: u8) -> io::Result<()> {
+        writeln!(self.out, "🎄 {ANSI_BOLD}Part {part}{ANSI_RESET} 🎄")
+    }
+
+    fn part_solved(&mut self, report: &SolveReport) -> io::Result<()> {
+        let verdict = report
+            .verdict()
+            .map(|verdict| format!(" {verdict}"))
+            .unwrap_or_default();
+
+        match &report.status {
+            Status::Solved => {
+                self.total += report.elapsed;
+                if let Some((_, total)) = self.year.as_mut() {
+                    *total += report.elapsed;
+                }
+                match report.bench {
+                    Some(bench) => writeln!(
+                        self.out,
+                        "{}{verdict} {ANSI_ITALIC}(median: {:.2?}, min: {:.2?}, mean: {:.2?}, stddev: {:.2?}, runs: {}){ANSI_RESET}",
+                        report.answer, bench.median, bench.min, bench.mean, bench.stddev, bench.runs
+                    ),
+                    None => writeln!(
+                        self.out,
+                        "{}{verdict} {ANSI_ITALIC}(elapsed: {:.2?}){ANSI_RESET}",
+                        report.answer, report.elapsed
+                    ),
+                }
+            }
+            Status::NotSolved => writeln!(self.out, "not solved.{verdict}"),
+            Status::Panicked(message) => writeln!(self.out, "panicked: {message}{verdict}"),
+            Status::TimedOut(timeout) => {
+                writeln!(self.out, "timed out after {timeout:.2?}{verdict}")
+            }
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.finish_year()?;
+        writeln!(
+            self.out,
+            "{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET}",
+            self.total.as_secs_f64() * 1000_f64
+        )
+    }
+}
+
+/// A plain table without colors, printed once all days have run.
+pub struct TableReporter<W: Write> {
+    out: W,
+    reports: Vec<SolveReport>,
+}
+
+impl<W: Write> TableReporter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            reports: vec![],
+        }
+    }
+}
+
+impl<W: Write> Reporter for TableReporter<W> {
+    fn day_skipped(&mut self, year: u16, day: u8, parts: &[u8]) -> io::Result<()> {
+        skipped_reports(year, day, parts)
+            .iter()
+            .try_for_each(|report| self.part_solved(report))
+    }
+
+    fn part_solved(&mut self, report: &SolveReport) -> io::Result<()> {
+        self.reports.push(report.clone());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let benched = self.reports.iter().any(|report| report.bench.is_some());
+        let verified = self.reports.iter().any(|report| report.expected.is_some());
+        let several_years = self
+            .reports
+            .iter()
+            .any(|report| report.year != self.reports[0].year);
+
+        let mut header = if several_years { vec!["year"] } else { vec![] };
+        header.extend(["day", "part", "answer", "elapsed"]);
+        if benched {
+            header.extend(["min", "mean", "stddev", "runs"]);
+        }
+        if verified {
+            header.push("check");
+        }
+
+        let rows: Vec<Vec<String>> = self
+            .reports
+            .iter()
+            .map(|report| {
+                let mut row = if several_years {
+                    vec![report.year.to_string()]
+                } else {
+                    vec![]
+                };
+                row.extend([format!("{:02}", report.day), report.part.to_string()]);
+
+                if report.solved() {
+                    row.extend([report.answer.clone(), format!("{:.2?}", report.elapsed)]);
+                } else {
+                    row.extend([report.status.to_string(), "-".into()]);
+                }
+
+                match report.bench {
+                    Some(bench) => row.extend([
+                        format!("{:.2?}", bench.min),
+                        format!("{:.2?}", bench.mean),
+                        format!("{:.2?}", bench.stddev),
+                        bench.runs.to_string(),
+                    ]),
+                    None if benched => row.extend(["-", "-", "-", "-"].map(String::from)),
+                    None => {}
+                }
+
+                if verified {
+                    row.push(report.verdict().unwrap_or_else(|| "-".into()));
+                }
+
+                row
+            })
+            .collect();
+
+        let header: Vec<String> = header.into_iter().map(String::from).collect();
+        let widths: Vec<usize> = (0..header.len())
+            .map(|column| {
+                rows.iter()
+                    .chain([&header])
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        for row in [&header].into_iter().chain(&rows) {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(self.out, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// One `key=value` line per part, meant to be consumed by other tools.
+pub struct RecordReporter<W: Write> {
+    out: W,
+}
+
+impl<W: Write> RecordReporter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+}
+
+impl<W: Write> Reporter for RecordReporter<W> {
+    fn day_skipped(&mut self, year: u16, day: u8, parts: &[u8]) -> io::Result<()> {
+        skipped_reports(year, day, parts)
+            .iter()
+            .try_for_each(|report| self.part_solved(report))
+    }
+
+    fn part_solved(&mut self, report: &SolveReport) -> io::Result<()> {
+        write!(
+            self.out,
+            "year={} day={} part={} solved={} elapsed_ns={}",
+            report.year,
+            report.day,
+            report.part,
+            report.solved(),
+            report.elapsed.as_nanos(),
+        )?;
+
+        if let Some(bench) = report.bench {
+            write!(
+                self.out,
+                " runs={} min_ns={} median_ns={} mean_ns={} stddev_ns={}",
+                bench.runs,
+                bench.min.as_nanos(),
+                bench.median.as_nanos(),
+                bench.mean.as_nanos(),
+                bench.stddev.as_nanos()
+            )?;
+        }
+
+        if let Some(correct) = report.correct() {
+            write!(self.out, " correct={correct}")?;
+        }
+
+        if let Some(message) = report.status.message() {
+            write!(self.out, " message={message:?}")?;
+        }
+
+        writeln!(self.out, " answer={:?}", report.answer)
+    }
+}
+
+/// Reports of a day without input, so that tables and machine-readable output have a row for
+/// every selected part.
+fn skipped_reports(year: u16, day: u8, parts: &[u8]) -> Vec<SolveReport> {
+    parts
+        .iter()
+        .map(|&part| SolveReport {
+            year,
+            day,
+            part,
+            answer: String::new(),
+            elapsed: Duration::ZERO,
+            status: Status::NotSolved,
+            bench: None,
+            expected: None,
+        })
+        .collect()
+}
+
+/// A JSON array with one object per part, written once all days have run.
+pub struct JsonReporter<W: Write> {
+    out: W,
+    records: Vec<serde_json::Value>,
+}
+
+impl<W: Write> JsonReporter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            records: vec![],
+        }
+    }
+}
+
+impl<W: Write> Reporter for JsonReporter<W> {
+    fn day_skipped(&mut self, year: u16, day: u8, parts: &[u8]) -> io::Result<()> {
+        skipped_reports(year, day, parts)
+            .iter()
+            .try_for_each(|report| self.part_solved(report))
+    }
+
+    fn part_solved(&mut self, report: &SolveReport) -> io::Result<()> {
+        self.records.push(json!({
+            "year": report.year,
+            "day": report.day,
+            "part": report.part,
+            "answer": if report.solved() { Some(&report.answer) } else { None },
+            "elapsed_ns": report.elapsed.as_nanos() as u64,
+            "status": report.status.to_string(),
+            "message": report.status.message(),
+            "bench": report.bench.map(|bench| json!({
+                "runs": bench.runs,
+                "min_ns": bench.min.as_nanos() as u64,
+                "median_ns": bench.median.as_nanos() as u64,
+                "mean_ns": bench.mean.as_nanos() as u64,
+                "stddev_ns": bench.stddev.as_nanos() as u64,
+            })),
+            "expected": report.expected,
+            "correct": report.correct(),
+        }));
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        serde_json::to_writer_pretty(&mut self.out, &self.records)?;
+        writeln!(self.out)
+    }
+}
+
+/// Comma-separated values with a header row, one row per part.
+pub struct CsvReporter<W: Write> {
+    out: W,
+    header_written: bool,
+}
+
+impl<W: Write> CsvReporter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            header_written: false,
+        }
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+impl<W: Write> Reporter for CsvReporter<W> {
+    fn day_skipped(&mut self, year: u16, day: u8, parts: &[u8]) -> io::Result<()> {
+        skipped_reports(year, day, parts)
+            .iter()
+            .try_for_each(|report| self.part_solved(report))
+    }
+
+    fn part_solved(&mut self, report: &SolveReport) -> io::Result<()> {
+        if !self.header_written {
+            writeln!(
+                self.out,
+                "year,day,part,answer,elapsed_ns,status,message,runs,min_ns,median_ns,mean_ns,stddev_ns,expected,correct"
+            )?;
+            self.header_written = true;
+        }
+
+        let bench = match report.bench {
+            Some(bench) => format!(
+                "{},{},{},{},{}",
+                bench.runs,
+                bench.min.as_nanos(),
+                bench.median.as_nanos(),
+                bench.mean.as_nanos(),
+                bench.stddev.as_nanos()
+            ),
+            None => ",,,,".into(),
+        };
+
+        writeln!(
+            self.out,
+            "{},{},{},{},{},{},{},{},{},{}",
+            report.year,
+            report.day,
+            report.part,
+            csv_field(&report.answer),
+            report.elapsed.as_nanos(),
+            report.status,
+            csv_field(&report.status.message().unwrap_or_default()),
+            bench,
+            csv_field(report.expected.as_deref().unwrap_or_default()),
+            report
+                .correct()
+                .map(|correct| correct.to_string())
+                .unwrap_or_default()
+        )
+    }
+}
+
+/// The output formats of the all-days runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Table,
+    Records,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn reporter<'a>(&self, out: impl Write + 'a) -> Box<dyn Reporter + 'a> {
+        match self {
+            Format::Text => Box::new(TextReporter::new(out)),
+            Format::Table => Box::new(TableReporter::new(out)),
+            Format::Records => Box::new(RecordReporter::new(out)),
+            Format::Json => Box::new(JsonReporter::new(out)),
+            Format::Csv => Box::new(CsvReporter::new(out)),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "table" => Ok(Format::Table),
+            "records" => Ok(Format::Records),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{s}\", expected one of: text, table, records, json, csv"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(day: u8, part: u8, answer: Option<&str>, elapsed_ms: u64) -> SolveReport {
+        SolveReport {
+            year: 2022,
+            day,
+            part,
+            answer: answer.unwrap_or_default().into(),
+            elapsed: Duration::from_millis(elapsed_ms),
+            status: match answer {
+                Some(_) => Status::Solved,
+                None => Status::NotSolved,
+            },
+            bench: None,
+            expected: None,
+        }
+    }
+
+    fn render(reporter: &mut dyn Reporter) {
+        reporter.day_started(2022, 1).unwrap();
+        reporter
+            .part_solved(&report(1, 1, Some("24000"), 2))
+            .unwrap();
+        reporter.part_solved(&report(1, 2, None, 1)).unwrap();
+        reporter.day_started(2022, 2).unwrap();
+        reporter.day_skipped(2022, 2, &[1, 2]).unwrap();
+        reporter.finish().unwrap();
+    }
+
+    #[test]
+    fn test_text_reporter() {
+        let mut out = vec![];
+        render(&mut TextReporter::new(&mut out));
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains(&format!("24000 {ANSI_ITALIC}(elapsed: 2.00ms){ANSI_RESET}")));
+        assert!(out.contains("not solved."));
+        assert!(out.ends_with(&format!("{ANSI_ITALIC}2.00ms{ANSI_RESET}\n")));
+    }
+
+    #[test]
+    fn test_table_reporter() {
+        let mut out = vec![];
+        render(&mut TableReporter::new(&mut out));
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day  part  answer      elapsed\n01   1     24000       2.00ms\n01   2     not solved  -\n02   1     not solved  -\n02   2     not solved  -\n"
+        );
+    }
+
+    #[test]
+    fn test_year_totals() {
+        let mut earlier = report(1, 1, Some("7"), 3);
+        earlier.year = 2021;
+
+        let mut out = vec![];
+        let mut reporter = TextReporter::new(&mut out);
+        reporter.year_started(2021).unwrap();
+        reporter.part_solved(&earlier).unwrap();
+        reporter.year_started(2022).unwrap();
+        reporter
+            .part_solved(&report(1, 1, Some("24000"), 2))
+            .unwrap();
+        reporter.finish().unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains(&format!("Total 2021:{ANSI_RESET} {ANSI_ITALIC}3.00ms")));
+        assert!(out.contains(&format!("Total 2022:{ANSI_RESET} {ANSI_ITALIC}2.00ms")));
+        assert!(out.ends_with(&format!("{ANSI_ITALIC}5.00ms{ANSI_RESET}\n")));
+
+        let mut out = vec![];
+        let mut reporter = TableReporter::new(&mut out);
+        reporter.part_solved(&earlier).unwrap();
+        reporter
+            .part_solved(&report(1, 1, Some("24000"), 2))
+            .unwrap();
+        reporter.finish().unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "year  day  part  answer  elapsed\n2021  01   1     7       3.00ms\n2022  01   1     24000   2.00ms\n"
+        );
+    }
+
+    #[test]
+    fn test_record_reporter() {
+        let mut out = vec![];
+        render(&mut RecordReporter::new(&mut out));
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "year=2022 day=1 part=1 solved=true elapsed_ns=2000000 answer=\"24000\"\nyear=2022 day=1 part=2 solved=false elapsed_ns=1000000 answer=\"\"\nyear=2022 day=2 part=1 solved=false elapsed_ns=0 answer=\"\"\nyear=2022 day=2 part=2 solved=false elapsed_ns=0 answer=\"\"\n"
+        );
+    }
+
+    #[test]
+    fn test_bench_columns() {
+        let mut benched = report(1, 1, Some("24000"), 2);
+        benched.bench = Some(BenchStats {
+            runs: 10,
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(2),
+            mean: Duration::from_millis(3),
+            stddev: Duration::from_millis(4),
+        });
+
+        let mut out = vec![];
+        let mut reporter = TableReporter::new(&mut out);
+        reporter.part_solved(&benched).unwrap();
+        reporter.part_solved(&report(1, 2, None, 1)).unwrap();
+        reporter.finish().unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day  part  answer      elapsed  min     mean    stddev  runs\n01   1     24000       2.00ms   1.00ms  3.00ms  4.00ms  10\n01   2     not solved  -        -       -       -       -\n"
+        );
+
+        let mut out = vec![];
+        RecordReporter::new(&mut out).part_solved(&benched).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "year=2022 day=1 part=1 solved=true elapsed_ns=2000000 runs=10 min_ns=1000000 median_ns=2000000 mean_ns=3000000 stddev_ns=4000000 answer=\"24000\"\n"
+        );
+    }
+
+    #[test]
+    fn test_verification() {
+        let mut correct = report(1, 1, Some("24000"), 2);
+        correct.expected = Some("24000".into());
+        let mut wrong = report(1, 2, Some("45001"), 1);
+        wrong.expected = Some("45000".into());
+        let mut unsolved = report(2, 1, None, 1);
+        unsolved.expected = Some("15".into());
+
+        assert_eq!(correct.correct(), Some(true));
+        assert_eq!(wrong.correct(), Some(false));
+        assert_eq!(unsolved.correct(), Some(false));
+        assert_eq!(report(2, 2, Some("12"), 1).correct(), None);
+
+        let mut out = vec![];
+        let mut reporter = TextReporter::new(&mut out);
+        for report in [&correct, &wrong, &unsolved] {
+            reporter.part_solved(report).unwrap();
+        }
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains("24000 ✔ "));
+        assert!(out.contains("45001 ✘ (expected: 45000) "));
+        assert!(out.contains("not solved. ✘ (expected: 15)"));
+    }
+
+    #[test]
+    fn test_json_reporter() {
+        let mut out = vec![];
+        render(&mut JsonReporter::new(&mut out));
+        let records: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(records.as_array().unwrap().len(), 4);
+        assert_eq!(
+            records[0],
+            json!({ "year": 2022, "day": 1, "part": 1, "answer": "24000", "elapsed_ns": 2000000, "status": "solved", "message": null, "bench": null, "expected": null, "correct": null })
+        );
+        assert_eq!(records[1]["answer"], serde_json::Value::Null);
+        assert_eq!(records[3]["day"], 2);
+        assert_eq!(records[3]["status"], "not solved");
+    }
+
+    #[test]
+    fn test_csv_reporter() {
+        let mut out = vec![];
+        render(&mut CsvReporter::new(&mut out));
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "year,day,part,answer,elapsed_ns,status,message,runs,min_ns,median_ns,mean_ns,stddev_ns,expected,correct\n2022,1,1,24000,2000000,solved,,,,,,,,\n2022,1,2,,1000000,not solved,,,,,,,,\n2022,2,1,,0,not solved,,,,,,,,\n2022,2,2,,0,not solved,,,,,,,,\n"
+        );
+
+        let mut out = vec![];
+        let mut reporter = CsvReporter::new(&mut out);
+        reporter.day_skipped(2022, 2, &[1]).unwrap();
+        assert_eq!(
+            String::from_utf8(out)
+                .unwrap()
+                .lines()
+                .skip(1)
+                .collect::<Vec<_>>(),
+            vec!["2022,2,1,,0,not solved,,,,,,,,"]
+        );
+
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+
+    #[test]
+    fn test_failure_messages() {
+        let mut panicked = report(1, 1, None, 1);
+        panicked.status = Status::Panicked("index out of bounds".into());
+        let mut timed_out = report(1, 2, None, 30_000);
+        timed_out.status = Status::TimedOut(Duration::from_secs(30));
+
+        let mut out = vec![];
+        let mut reporter = JsonReporter::new(&mut out);
+        reporter.part_solved(&panicked).unwrap();
+        reporter.part_solved(&timed_out).unwrap();
+        reporter.finish().unwrap();
+        let records: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(records[0]["message"], "index out of bounds");
+        assert_eq!(records[1]["message"], "after 30.00s");
+
+        let mut out = vec![];
+        CsvReporter::new(&mut out).part_solved(&panicked).unwrap();
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .ends_with("\n2022,1,1,,1000000,panicked,index out of bounds,,,,,,,\n"));
+
+        let mut out = vec![];
+        RecordReporter::new(&mut out)
+            .part_solved(&timed_out)
+            .unwrap();
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .ends_with(" message=\"after 30.00s\" answer=\"\"\n"));
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
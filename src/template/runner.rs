@@ -0,0 +1,374 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    any::Any,
+    fmt::Display,
+    io,
+    panic::{self, AssertUnwindSafe},
+    process,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::{
+    answers,
+    baseline::BaselineOptions,
+    bench,
+    input_cache::InputSource,
+    report::{Reporter, SolveReport, Status},
+};
+
+/// Settings shared by `cargo solve` and `cargo all`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Run every solved part this many more times and report statistics over them.
+    pub bench_runs: Option<usize>,
+    /// Give up on a part that takes longer than this. Zero disables the limit.
+    pub timeout: Option<Duration>,
+    pub baseline: BaselineOptions,
+    /// Only the own input in `src/inputs` is checked against the answers in `src/answers`.
+    pub input: InputSource,
+}
+
+impl RunOptions {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            bench_runs: args.opt_value_from_str("--bench")?,
+            timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+            baseline: BaselineOptions::from_args(args)?,
+            input: args.opt_value_from_str("--input")?.unwrap_or_default(),
+        })
+    }
+
+    /// Parses the options of a day binary, exiting on invalid or unknown arguments.
+    pub fn from_env() -> Self {
+        let mut args = pico_args::Arguments::from_env();
+        let options = Self::from_args(&mut args).and_then(|options| {
+            finish_args(args)?;
+            Ok(options)
+        });
+        match options {
+            Ok(options) => options,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Fails on arguments that were not parsed, so that a typo like `--parts 2` isn't ignored.
+pub fn finish_args(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    let unknown: Vec<String> = args
+        .finish()
+        .iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    if unknown.is_empty() {
+        return Ok(());
+    }
+    Err(pico_args::Error::Utf8ArgumentParsingFailed {
+        value: unknown.join(" "),
+        cause: "unknown argument".into(),
+    })
+}
+
+/// Parses a number of seconds, rejecting negative, infinite and NaN ones.
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    let seconds: f64 = value
+        .parse()
+        .map_err(|_| format!("\"{value}\" is not a number of seconds"))?;
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|e| format!("\"{value}\" is not a valid timeout: {e}"))
+}
+
+enum Outcome<T> {
+    Finished(thread::Result<T>, Duration),
+    TimedOut(Duration),
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> Outcome<T> {
+    let timer = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    Outcome::Finished(result, timer.elapsed())
+}
+
+/// Runs `f` on a separate thread and stops waiting for it after `timeout`.
+/// The thread can't be cancelled and keeps running in the background until the process exits.
+fn timed_with_limit<T: Send + 'static>(
+    day: u8,
+    part: u8,
+    f: impl FnOnce() -> T + Send + 'static,
+    timeout: Duration,
+) -> Outcome<T> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::Builder::new()
+        .name(format!("day {day:02} part {part}"))
+        // solutions may recurse deeply, give them as much stack as the main thread.
+        .stack_size(8 * 1024 * 1024)
+        .spawn(move || sender.send(timed(f)))
+        .expect("could not spawn solver thread");
+
+    receiver
+        .recv_timeout(timeout)
+        .unwrap_or(Outcome::TimedOut(timeout))
+}
+
+/// Runs `f` with its panics caught and, if there is one, within `timeout`.
+fn isolated<T: Send + 'static>(
+    day: u8,
+    part: u8,
+    f: impl FnOnce() -> T + Send + 'static,
+    timeout: Option<Duration>,
+) -> Outcome<T> {
+    match timeout {
+        Some(timeout) => timed_with_limit(day, part, f, timeout),
+        None => timed(f),
+    }
+}
+
+/// Runs `solver` on `input` and records its answer and how long it took.
+/// The report is of `DEFAULT_YEAR`, `Solution::solve` moves it to the year of the day.
+/// A panic in `solver` is caught and reported as [`Status::Panicked`],
+/// exceeding `options.timeout` is reported as [`Status::TimedOut`].
+/// The repeated runs of `--bench` are held to the same rules, with a limit of their own.
+pub fn solve_part<T: Display + Send + 'static>(
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> Option<T> + Clone + Send + 'static,
+    input: &str,
+    options: &RunOptions,
+) -> SolveReport {
+    let timeout = options.timeout.filter(|timeout| !timeout.is_zero());
+    let run = {
+        let (solver, input) = (solver.clone(), input.to_string());
+        move || solver(&input)
+    };
+
+    let (answer, mut status, mut elapsed) = match isolated(day, part, run, timeout) {
+        Outcome::Finished(Ok(Some(answer)), elapsed) => {
+            (answer.to_string(), Status::Solved, elapsed)
+        }
+        Outcome::Finished(Ok(None), elapsed) => (String::new(), Status::NotSolved, elapsed),
+        Outcome::Finished(Err(payload), elapsed) => (
+            String::new(),
+            Status::Panicked(panic_message(payload)),
+            elapsed,
+        ),
+        Outcome::TimedOut(timeout) => (String::new(), Status::TimedOut(timeout), timeout),
+    };
+
+    let bench = match (&status, options.bench_runs) {
+        (Status::Solved, Some(runs)) => {
+            let input = input.to_string();
+            let repeat = move || bench::bench(|| solver(&input), runs);
+            match isolated(day, part, repeat, timeout) {
+                Outcome::Finished(Ok(bench), _) => bench,
+                Outcome::Finished(Err(payload), _) => {
+                    status = Status::Panicked(panic_message(payload));
+                    None
+                }
+                Outcome::TimedOut(timeout) => {
+                    status = Status::TimedOut(timeout);
+                    elapsed = timeout;
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+
+    if let Some(bench) = bench {
+        elapsed = bench.median;
+    }
+
+    SolveReport {
+        year: crate::DEFAULT_YEAR,
+        day,
+        part,
+        answer,
+        elapsed,
+        status,
+        bench,
+        expected: None,
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+/// Announces `part` to `reporter`, solves it and hands the report to `reporter`.
+pub fn run_part<T: Display + Send + 'static>(
+    reporter: &mut dyn Reporter,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> Option<T> + Clone + Send + 'static,
+    input: &str,
+    options: &RunOptions,
+) -> io::Result<SolveReport> {
+    report_part(reporter, day, part, options, || {
+        solve_part(day, part, solver, input, options)
+    })
+}
+
+/// Like `run_part`, for callers that solve the part themselves.
+/// Attaches the accepted answer of the year of the report to it before handing it to `reporter`.
+pub fn report_part(
+    reporter: &mut dyn Reporter,
+    day: u8,
+    part: u8,
+    options: &RunOptions,
+    solve: impl FnOnce() -> SolveReport,
+) -> io::Result<SolveReport> {
+    reporter.part_started(day, part)?;
+    let mut report = solve();
+    if options.input == InputSource::Cache {
+        report.expected = answers::expected_answer(report.year, day, part);
+    }
+    reporter.part_solved(&report)?;
+    Ok(report)
+}
+
+/// Whether any of `reports` contradicts an accepted answer.
+pub fn has_wrong_answers(reports: &[SolveReport]) -> bool {
+    reports.iter().any(|report| report.correct() == Some(false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[test]
+    fn test_solve_part() {
+        let options = RunOptions::default();
+
+        let solved = solve_part(1, 2, |input| Some(input.len()), "abc", &options);
+        assert_eq!((solved.day, solved.part), (1, 2));
+        assert_eq!(solved.answer, "3");
+        assert_eq!(solved.status, Status::Solved);
+        assert_eq!(solved.bench, None);
+
+        let unsolved = solve_part(1, 1, |_| None::<u32>, "abc", &options);
+        assert_eq!(unsolved.answer, "");
+        assert_eq!(unsolved.status, Status::NotSolved);
+
+        let panicked = solve_part(
+            1,
+            1,
+            |_| -> Option<u32> { panic!("oh no") },
+            "abc",
+            &options,
+        );
+        assert_eq!(panicked.status, Status::Panicked("oh no".into()));
+    }
+
+    #[test]
+    fn test_solve_part_bench() {
+        let options = RunOptions {
+            bench_runs: Some(5),
+            ..Default::default()
+        };
+
+        let solved = solve_part(1, 1, |input| Some(input.len()), "abc", &options);
+        assert_eq!(solved.bench.map(|bench| bench.runs), Some(5));
+
+        let unsolved = solve_part(1, 1, |_| None::<u32>, "abc", &options);
+        assert_eq!(unsolved.bench, None);
+
+        // only the repeated runs panic.
+        let calls = Arc::new(AtomicUsize::new(0));
+        let flaky = move |_: &str| {
+            assert!(calls.fetch_add(1, Ordering::Relaxed) < 3, "third time");
+            Some(1)
+        };
+        let panicked = solve_part(1, 1, flaky, "abc", &options);
+        assert_eq!(panicked.status, Status::Panicked("third time".into()));
+        assert_eq!(panicked.bench, None);
+    }
+
+    #[test]
+    fn test_solve_part_bench_timeout() {
+        let options = RunOptions {
+            bench_runs: Some(100),
+            timeout: Some(Duration::from_millis(50)),
+            ..Default::default()
+        };
+
+        let slow = |_: &str| {
+            thread::sleep(Duration::from_millis(10));
+            Some(1)
+        };
+        let timed_out = solve_part(1, 1, slow, "abc", &options);
+        assert_eq!(
+            timed_out.status,
+            Status::TimedOut(Duration::from_millis(50))
+        );
+        assert_eq!(timed_out.bench, None);
+    }
+
+    #[test]
+    fn test_finish_args() {
+        let args =
+            |args: &[&str]| pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+
+        let mut known = args(&["--bench", "5"]);
+        assert!(RunOptions::from_args(&mut known).is_ok());
+        assert!(finish_args(known).is_ok());
+
+        let mut unknown = args(&["--bench", "5", "--parts", "2"]);
+        RunOptions::from_args(&mut unknown).unwrap();
+        assert_eq!(
+            finish_args(unknown).unwrap_err().to_string(),
+            "failed to parse '--parts 2': unknown argument"
+        );
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_timeout("0"), Ok(Duration::ZERO));
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("NaN").is_err());
+        assert!(parse_timeout("inf").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+
+    #[test]
+    fn test_solve_part_timeout() {
+        let options = RunOptions {
+            timeout: Some(Duration::from_millis(20)),
+            ..Default::default()
+        };
+
+        let slow = |_: &str| {
+            thread::sleep(Duration::from_secs(1));
+            Some(1)
+        };
+        let timed_out = solve_part(16, 2, slow, "abc", &options);
+        assert_eq!(
+            timed_out.status,
+            Status::TimedOut(Duration::from_millis(20))
+        );
+
+        let solved = solve_part(1, 1, |input| Some(input.len()), "abc", &options);
+        assert_eq!(solved.answer, "3");
+
+        let panicked = solve_part(1, 1, |_| -> Option<u32> { panic!("oh no") }, "", &options);
+        assert_eq!(panicked.status, Status::Panicked("oh no".into()));
+    }
+}
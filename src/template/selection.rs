@@ -0,0 +1,105 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeSet;
+
+use super::solution::Solution;
+
+/// Which days and parts `cargo all` runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    /// All years if `None`.
+    pub year: Option<u16>,
+    /// All days if `None`.
+    pub days: Option<BTreeSet<u8>>,
+    /// Both parts if `None`.
+    pub part: Option<u8>,
+    /// Leave out days marked as slow in their `solution!`.
+    pub skip_slow: bool,
+}
+
+impl Selection {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            year: args.opt_value_from_str(["-y", "--year"])?,
+            days: args.opt_value_from_fn("--days", parse_days)?,
+            part: args.opt_value_from_fn("--part", parse_part)?,
+            skip_slow: args.contains("--skip-slow"),
+        })
+    }
+
+    pub fn includes(&self, solution: &dyn Solution) -> bool {
+        let year_selected = self.year.is_none_or(|year| year == solution.year());
+        let day_selected = self
+            .days
+            .as_ref()
+            .is_none_or(|days| days.contains(&solution.day()));
+
+        year_selected && day_selected && !(self.skip_slow && solution.slow())
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.trim().parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("\"{value}\" is not a day between 1 and 25")),
+    }
+}
+
+/// Parses a comma-separated list of days and inclusive ranges, e.g. `10-18,22`.
+pub fn parse_days(value: &str) -> Result<BTreeSet<u8>, String> {
+    value
+        .split(',')
+        .map(|range| match range.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!("range \"{range}\" ends before it starts"));
+                }
+                Ok(start..=end)
+            }
+            None => parse_day(range).map(|day| day..=day),
+        })
+        .try_fold(BTreeSet::new(), |mut days, range| {
+            days.extend(range?);
+            Ok(days)
+        })
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value.parse() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("\"{value}\" is not a part, expected 1 or 2")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(
+            parse_days("10-18,22"),
+            Ok(BTreeSet::from([10, 11, 12, 13, 14, 15, 16, 17, 18, 22]))
+        );
+        assert_eq!(parse_days("3"), Ok(BTreeSet::from([3])));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("18-10").is_err());
+        assert!(parse_days("1,x").is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(parse_part("2"), Ok(2));
+        assert!(parse_part("3").is_err());
+    }
+}
@@ -0,0 +1,149 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+
+use super::{
+    report::SolveReport,
+    runner::{solve_part, RunOptions},
+};
+
+/// A solved day that can be run without knowing the answer types of its parts.
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    /// Whether the day takes long enough to be left out by `cargo all --skip-slow`.
+    fn slow(&self) -> bool;
+    fn part_one(&self, input: &str) -> Option<String>;
+    fn part_two(&self, input: &str) -> Option<String>;
+    /// Solves `part` while timing the solver itself, excluding the conversion of its answer.
+    fn solve(&self, part: u8, input: &str, options: &RunOptions) -> SolveReport;
+}
+
+/// Binds the `part_one` and `part_two` functions of a day to its number.
+/// Construct this via the `solution!` macro in `src/bin/NN.rs`.
+pub struct Day<A, B> {
+    year: u16,
+    day: u8,
+    slow: bool,
+    part_one: fn(&str) -> Option<A>,
+    part_two: fn(&str) -> Option<B>,
+}
+
+impl<A, B> Day<A, B> {
+    pub const fn new(
+        day: u8,
+        part_one: fn(&str) -> Option<A>,
+        part_two: fn(&str) -> Option<B>,
+    ) -> Self {
+        Self {
+            year: crate::DEFAULT_YEAR,
+            day,
+            slow: false,
+            part_one,
+            part_two,
+        }
+    }
+
+    pub const fn slow(self) -> Self {
+        Self { slow: true, ..self }
+    }
+
+    /// Moves the day from `DEFAULT_YEAR` to `year`.
+    pub const fn in_year(self, year: u16) -> Self {
+        Self { year, ..self }
+    }
+}
+
+impl<A, B> Solution for Day<A, B>
+where
+    A: Display + Send + 'static,
+    B: Display + Send + 'static,
+{
+    fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn slow(&self) -> bool {
+        self.slow
+    }
+
+    fn part_one(&self, input: &str) -> Option<String> {
+        (self.part_one)(input).map(|result| result.to_string())
+    }
+
+    fn part_two(&self, input: &str) -> Option<String> {
+        (self.part_two)(input).map(|result| result.to_string())
+    }
+
+    fn solve(&self, part: u8, input: &str, options: &RunOptions) -> SolveReport {
+        let report = match part {
+            1 => solve_part(self.day, part, self.part_one, input, options),
+            2 => solve_part(self.day, part, self.part_two, input, options),
+            _ => panic!("a day only has two parts, got part {part}"),
+        };
+        SolveReport {
+            year: self.year,
+            ..report
+        }
+    }
+}
+
+/// Exposes a day as `SOLUTION` for the registry in `src/days.rs` and generates its `main`.
+/// Expects `part_one` and `part_two` to be in scope.
+/// Use `solution!(DAY, slow)` to mark a day that `cargo all --skip-slow` should leave out,
+/// and `solution!(DAY, year = YEAR)` for a day of another year than `DEFAULT_YEAR`.
+#[macro_export]
+macro_rules! solution {
+    ($day:expr, year = $year:expr, slow) => {
+        advent_of_code::solution!(@main $day, advent_of_code::Day::new($day, part_one, part_two).in_year($year).slow());
+    };
+    ($day:expr, year = $year:expr) => {
+        advent_of_code::solution!(@main $day, advent_of_code::Day::new($day, part_one, part_two).in_year($year));
+    };
+    ($day:expr, slow) => {
+        advent_of_code::solution!(@main $day, advent_of_code::Day::new($day, part_one, part_two).slow());
+    };
+    ($day:expr) => {
+        advent_of_code::solution!(@main $day, advent_of_code::Day::new($day, part_one, part_two));
+    };
+    (@main $day:expr, $solution:expr) => {
+        pub static SOLUTION: &dyn advent_of_code::Solution = &$solution;
+
+        fn main() {
+            use advent_of_code::template::{report::TextReporter, runner::report_part};
+
+            let options = advent_of_code::template::runner::RunOptions::from_env();
+            let input = &options.input.read(SOLUTION.year(), $day).unwrap_or_else(|e| {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                });
+            let mut reporter = TextReporter::new(std::io::stdout());
+            let reports = [1, 2].map(|part| {
+                report_part(&mut reporter, $day, part, &options, || {
+                    SOLUTION.solve(part, input, &options)
+                })
+                .expect("could not write to stdout")
+            });
+
+            // timings of another input say nothing about the baseline of the day.
+            if options.input != advent_of_code::template::input_cache::InputSource::Cache {
+                if options.baseline.save || options.baseline.compare {
+                    eprintln!("Ignoring the baseline, it only applies to the input in `src/inputs`.");
+                }
+            } else if let Err(e) = options.baseline.apply(&reports) {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+
+            if advent_of_code::template::runner::has_wrong_answers(&reports) {
+                std::process::exit(1);
+            }
+        }
+    };
+}
@@ -0,0 +1,413 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fmt::Display, fs, io, path::Path, time::Duration};
+
+use regex::Regex;
+use serde_json::{json, Value};
+
+pub const DEFAULT_SUBMISSIONS_PATH: &str = "submissions.json";
+
+/// What Advent of Code made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// The answer was not checked because the previous one was submitted too recently.
+    TooRecent,
+    /// The part was solved before, or part one is not solved yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooRecent => "too recent",
+            Verdict::WrongLevel => "wrong level",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    fn from_str(verdict: &str) -> Option<Self> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Incorrect,
+        ]
+        .into_iter()
+        .find(|candidate| candidate.as_str() == verdict)
+    }
+
+    /// Whether the answer was actually checked, and should be remembered.
+    fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmitResponse {
+    pub verdict: Verdict,
+    /// How long Advent of Code asks to wait before the next submission.
+    pub wait: Option<Duration>,
+    pub message: String,
+}
+
+impl SubmitResponse {
+    /// Parses the message of the page returned after submitting an answer.
+    pub fn parse(message: &str) -> Self {
+        let verdict = if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Verdict::TooHigh
+            } else if message.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Incorrect
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        };
+
+        Self {
+            verdict,
+            wait: parse_wait(message),
+            message: message.trim().to_string(),
+        }
+    }
+}
+
+/// Reads "You have 1m 5s left to wait" and "Please wait one minute" style messages.
+fn parse_wait(message: &str) -> Option<Duration> {
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    if let Some(captures) = left.captures(message) {
+        let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = captures[2].parse().unwrap();
+        return Some(Duration::from_secs(minutes * 60 + seconds));
+    }
+
+    let minutes = Regex::new(r"[Pp]lease wait (one|\d+) minutes?").unwrap();
+    minutes.captures(message).map(|captures| {
+        let minutes = match &captures[1] {
+            "one" => 1,
+            minutes => minutes.parse().unwrap(),
+        };
+        Duration::from_secs(minutes * 60)
+    })
+}
+
+/// An answer that was submitted before.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Why an answer is not worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved(String),
+    KnownWrong,
+    AboveBound(String),
+    BelowBound(String),
+    RateLimited(Duration),
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with \"{answer}\".")
+            }
+            Rejection::KnownWrong => write!(f, "this answer was submitted before and is wrong."),
+            Rejection::AboveBound(bound) => {
+                write!(f, "this answer is too high, \"{bound}\" already was.")
+            }
+            Rejection::BelowBound(bound) => {
+                write!(f, "this answer is too low, \"{bound}\" already was.")
+            }
+            Rejection::RateLimited(wait) => {
+                write!(f, "submitted too recently, wait {}s.", wait.as_secs())
+            }
+        }
+    }
+}
+
+/// Every answer submitted so far, to avoid submitting answers that can't be right.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubmissionHistory {
+    attempts: Vec<Attempt>,
+    /// Unix timestamp in seconds before which Advent of Code won't check another answer.
+    not_before: u64,
+}
+
+impl SubmissionHistory {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let history: Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+        let invalid = |what: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("submission history without valid \"{what}\""),
+            )
+        };
+
+        let attempts = history["attempts"]
+            .as_array()
+            .ok_or_else(|| invalid("attempts"))?
+            .iter()
+            .map(|attempt| {
+                let number = |key: &str| attempt[key].as_u64().ok_or_else(|| invalid(key));
+                let string = |key: &str| attempt[key].as_str().ok_or_else(|| invalid(key));
+                Ok(Attempt {
+                    year: number("year")? as u16,
+                    day: number("day")? as u8,
+                    part: number("part")? as u8,
+                    answer: string("answer")?.to_string(),
+                    verdict: Verdict::from_str(string("verdict")?)
+                        .ok_or_else(|| invalid("verdict"))?,
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self {
+            attempts,
+            not_before: history["not_before"].as_u64().unwrap_or(0),
+        })
+    }
+
+    /// Like `load`, but starts out empty if nothing was submitted yet.
+    pub fn load_or_default(path: &Path) -> io::Result<Self> {
+        match Self::load(path) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            history => history,
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let attempts: Vec<Value> = self
+            .attempts
+            .iter()
+            .map(|attempt| {
+                json!({
+                    "year": attempt.year,
+                    "day": attempt.day,
+                    "part": attempt.part,
+                    "answer": attempt.answer,
+                    "verdict": attempt.verdict.as_str(),
+                })
+            })
+            .collect();
+        let history = json!({ "not_before": self.not_before, "attempts": attempts });
+
+        fs::write(path, serde_json::to_string_pretty(&history)? + "\n")
+    }
+
+    pub fn attempts(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| (attempt.year, attempt.day, attempt.part) == (year, day, part))
+    }
+
+    /// Checks `answer` against earlier attempts before it is submitted at unix time `now`.
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Rejection> {
+        let attempts: Vec<&Attempt> = self.attempts(year, day, part).collect();
+
+        if let Some(correct) = attempts.iter().find(|a| a.verdict == Verdict::Correct) {
+            return Err(Rejection::AlreadySolved(correct.answer.clone()));
+        }
+
+        if attempts.iter().any(|attempt| attempt.answer == answer) {
+            return Err(Rejection::KnownWrong);
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |verdict: Verdict| {
+                attempts
+                    .iter()
+                    .filter(move |attempt| attempt.verdict == verdict)
+                    .filter_map(|attempt| Some((attempt.answer.parse::<i128>().ok()?, attempt)))
+            };
+
+            if let Some((_, attempt)) = bound(Verdict::TooHigh)
+                .filter(|(high, _)| value >= *high)
+                .min_by_key(|(high, _)| *high)
+            {
+                return Err(Rejection::AboveBound(attempt.answer.clone()));
+            }
+
+            if let Some((_, attempt)) = bound(Verdict::TooLow)
+                .filter(|(low, _)| value <= *low)
+                .max_by_key(|(low, _)| *low)
+            {
+                return Err(Rejection::BelowBound(attempt.answer.clone()));
+            }
+        }
+
+        if now < self.not_before {
+            return Err(Rejection::RateLimited(Duration::from_secs(
+                self.not_before - now,
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Remembers the response to an answer submitted at unix time `now`.
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        response: &SubmitResponse,
+        now: u64,
+    ) {
+        if let Some(wait) = response.wait {
+            self.not_before = now + wait.as_secs();
+        }
+
+        if response.verdict.is_final() {
+            self.attempts.push(Attempt {
+                year,
+                day,
+                part,
+                answer: answer.to_string(),
+                verdict: response.verdict.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(verdict: Verdict, wait: Option<u64>) -> SubmitResponse {
+        SubmitResponse {
+            verdict,
+            wait: wait.map(Duration::from_secs),
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let correct = SubmitResponse::parse("That's the right answer! You are one gold star closer to collecting enough star fruit. [[Continue to Part Two]](/2022/day/1#part2)");
+        assert_eq!(correct.verdict, Verdict::Correct);
+        assert_eq!(correct.wait, None);
+
+        let too_high = SubmitResponse::parse("That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [[Return to Day 1]](/2022/day/1)");
+        assert_eq!(too_high.verdict, Verdict::TooHigh);
+        assert_eq!(too_high.wait, Some(Duration::from_secs(60)));
+
+        let too_low = SubmitResponse::parse("That's not the right answer; your answer is too low. Please wait 5 minutes before trying again.");
+        assert_eq!(too_low.verdict, Verdict::TooLow);
+        assert_eq!(too_low.wait, Some(Duration::from_secs(300)));
+
+        let too_recent = SubmitResponse::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 12s left to wait.");
+        assert_eq!(too_recent.verdict, Verdict::TooRecent);
+        assert_eq!(too_recent.wait, Some(Duration::from_secs(72)));
+
+        let wrong_level = SubmitResponse::parse(
+            "You don't seem to be solving the right level. Did you already complete it?",
+        );
+        assert_eq!(wrong_level.verdict, Verdict::WrongLevel);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut history = SubmissionHistory::default();
+        history.record(
+            2022,
+            1,
+            1,
+            "500",
+            &response(Verdict::TooHigh, Some(60)),
+            1000,
+        );
+        history.record(2022, 1, 1, "100", &response(Verdict::TooLow, None), 1100);
+        history.record(2022, 1, 1, "abc", &response(Verdict::Incorrect, None), 1100);
+        history.record(
+            2022,
+            1,
+            1,
+            "300",
+            &response(Verdict::TooRecent, Some(30)),
+            1100,
+        );
+
+        assert_eq!(
+            history.check(2022, 1, 1, "abc", 2000),
+            Err(Rejection::KnownWrong)
+        );
+        assert_eq!(
+            history.check(2022, 1, 1, "501", 2000),
+            Err(Rejection::AboveBound("500".into()))
+        );
+        assert_eq!(
+            history.check(2022, 1, 1, "-3", 2000),
+            Err(Rejection::BelowBound("100".into()))
+        );
+        assert_eq!(
+            history.check(2022, 1, 1, "300", 1110),
+            Err(Rejection::RateLimited(Duration::from_secs(20)))
+        );
+        assert_eq!(history.check(2022, 1, 1, "300", 2000), Ok(()));
+        assert_eq!(history.check(2022, 1, 2, "501", 2000), Ok(()));
+        assert_eq!(history.check(2021, 1, 1, "abc", 2000), Ok(()));
+
+        history.record(2022, 1, 1, "300", &response(Verdict::Correct, None), 2000);
+        assert_eq!(
+            history.check(2022, 1, 1, "301", 2000),
+            Err(Rejection::AlreadySolved("300".into()))
+        );
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join("aoc_submissions_test_save_and_load.json");
+
+        let mut history = SubmissionHistory::default();
+        history.record(2022, 3, 2, "42", &response(Verdict::TooLow, Some(60)), 1000);
+        history.record(2022, 3, 2, "CMZ", &response(Verdict::Correct, None), 1100);
+        history.save(&path).unwrap();
+        let loaded = SubmissionHistory::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, history);
+        assert_eq!(loaded.attempts(2022, 3, 2).count(), 2);
+    }
+}